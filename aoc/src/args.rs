@@ -0,0 +1,157 @@
+use std::path::PathBuf;
+
+use common::input::InputSource;
+use common::ReportFormat;
+
+pub const USAGE: &str =
+    "Usage: aoc run <day> <part> [--input <path>|-] [--explain <table|json>] [--rules <path>]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<&str> for Part {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Part must be either 1 or 2, got {}", value)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunCommand {
+    pub day: u8,
+    pub part: Part,
+    pub input: InputSource,
+    // explains the answer rather than only giving it
+    pub explain: Option<ReportFormat>,
+    // a file of rules to play by, in place of the rules the part plays by
+    pub rules: Option<PathBuf>,
+}
+
+impl RunCommand {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut args = args.iter();
+
+        match args.next().map(|arg| arg.as_str()) {
+            Some("run") => {}
+            Some(command) => return Err(format!("Unknown command {}\n{}", command, USAGE)),
+            None => return Err(USAGE.to_string()),
+        }
+
+        let day = match args.next() {
+            Some(day) => day
+                .parse::<u8>()
+                .map_err(|_| format!("Day must be a number, got {}", day))?,
+            None => return Err(USAGE.to_string()),
+        };
+
+        let part = match args.next() {
+            Some(part) => Part::try_from(part.as_str())?,
+            None => return Err(USAGE.to_string()),
+        };
+
+        let mut input = InputSource::Cache;
+        let mut explain = None;
+        let mut rules = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => match args.next() {
+                    Some(path) => input = InputSource::from(path.as_str()),
+                    None => return Err("Expected a path after --input".to_string()),
+                },
+                "--explain" => match args.next() {
+                    Some(format) => explain = Some(ReportFormat::try_from(format.as_str())?),
+                    None => return Err("Expected a format after --explain".to_string()),
+                },
+                "--rules" => match args.next() {
+                    Some(path) => rules = Some(PathBuf::from(path)),
+                    None => return Err("Expected a path after --rules".to_string()),
+                },
+                _ => return Err(format!("Unknown argument {}\n{}", arg, USAGE)),
+            }
+        }
+
+        Ok(Self {
+            day,
+            part,
+            input,
+            explain,
+            rules,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_args(input: &str) -> Vec<String> {
+        input.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn run_command_parses_successfully() -> Result<(), String> {
+        let command = RunCommand::parse(&to_args("run 5 2"))?;
+        assert_eq!(command.day, 5);
+        assert_eq!(command.part, Part::Two);
+        assert_eq!(command.input, InputSource::Cache);
+        assert_eq!(command.explain, None);
+        assert_eq!(command.rules, None);
+        Ok(())
+    }
+
+    #[test]
+    fn run_command_parses_input_path() -> Result<(), String> {
+        let command = RunCommand::parse(&to_args("run 9 1 --input example.txt"))?;
+        assert_eq!(command.day, 9);
+        assert_eq!(command.part, Part::One);
+        assert_eq!(
+            command.input,
+            InputSource::Path(PathBuf::from("example.txt"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn run_command_parses_stdin_input() -> Result<(), String> {
+        let command = RunCommand::parse(&to_args("run 7 2 --input -"))?;
+        assert_eq!(command.input, InputSource::Stdin);
+        Ok(())
+    }
+
+    #[test]
+    fn run_command_parses_explain_format() -> Result<(), String> {
+        let command = RunCommand::parse(&to_args("run 7 2 --explain json --input -"))?;
+        assert_eq!(command.explain, Some(ReportFormat::Json));
+        assert_eq!(command.input, InputSource::Stdin);
+        Ok(())
+    }
+
+    #[test]
+    fn run_command_parses_rules_path() -> Result<(), String> {
+        let command = RunCommand::parse(&to_args("run 7 1 --rules day7/rules/deuces-wild.txt"))?;
+        assert_eq!(
+            command.rules,
+            Some(PathBuf::from("day7/rules/deuces-wild.txt"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn run_command_rejects_invalid_arguments() {
+        assert!(RunCommand::parse(&to_args("")).is_err());
+        assert!(RunCommand::parse(&to_args("walk 5 1")).is_err());
+        assert!(RunCommand::parse(&to_args("run five 1")).is_err());
+        assert!(RunCommand::parse(&to_args("run 5 3")).is_err());
+        assert!(RunCommand::parse(&to_args("run 5 1 --input")).is_err());
+        assert!(RunCommand::parse(&to_args("run 7 1 --explain")).is_err());
+        assert!(RunCommand::parse(&to_args("run 7 1 --explain csv")).is_err());
+        assert!(RunCommand::parse(&to_args("run 7 1 --rules")).is_err());
+    }
+}
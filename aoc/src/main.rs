@@ -0,0 +1,66 @@
+use common::error::{Error, Result};
+use common::input::InputSource;
+use common::Solution;
+
+mod args;
+
+use args::{Part, RunCommand};
+
+fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(day3::Day3),
+        Box::new(day4::Day4),
+        Box::new(day5::Day5),
+        Box::new(day6::Day6),
+        Box::new(day7::Day7),
+        Box::new(day8::Day8),
+        Box::new(day9::Day9),
+    ]
+}
+
+fn process(command: &RunCommand) -> Result<String> {
+    let solution = solutions()
+        .into_iter()
+        .find(|solution| solution.day() == command.day)
+        .ok_or(Error::Unsolved(format!(
+            "No solution found for day {}",
+            command.day
+        )))?;
+
+    let input = command.input.read(command.day)?;
+
+    if let Some(path) = &command.rules {
+        let rules = InputSource::Path(path.clone()).read(command.day)?;
+        return match command.explain {
+            None => solution.solve_with_rules(&input, &rules),
+            Some(format) => solution.explain_with_rules(&input, &rules, format),
+        };
+    }
+
+    match (command.part, command.explain) {
+        (Part::One, None) => solution.part1(&input),
+        (Part::Two, None) => solution.part2(&input),
+        (Part::One, Some(format)) => solution.explain_part1(&input, format),
+        (Part::Two, Some(format)) => solution.explain_part2(&input, format),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match RunCommand::parse(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(2);
+        }
+    };
+
+    match process(&command) {
+        Ok(explanation) if command.explain.is_some() => println!("{explanation}"),
+        Ok(result) => println!("The result is: {result}"),
+        Err(error) => {
+            eprintln!("An error occured: {error}");
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// 1-based position within the puzzle input an error relates to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Location {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " at line {}, column {}", line, column),
+            (Some(line), None) => write!(f, " at line {}", line),
+            (None, Some(column)) => write!(f, " at column {}", column),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Missing {
+        expected: String,
+        location: Location,
+    },
+    InvalidNumber {
+        value: String,
+        location: Location,
+    },
+    InvalidCharacter {
+        character: char,
+        location: Location,
+    },
+    Invalid {
+        reason: String,
+        location: Location,
+    },
+    Input(String),
+    Unsolved(String),
+}
+
+impl Error {
+    pub fn missing(expected: &str) -> Self {
+        Error::Missing {
+            expected: expected.to_string(),
+            location: Location::default(),
+        }
+    }
+
+    pub fn invalid_number(value: &str) -> Self {
+        Error::InvalidNumber {
+            value: value.to_string(),
+            location: Location::default(),
+        }
+    }
+
+    pub fn invalid_character(character: char) -> Self {
+        Error::InvalidCharacter {
+            character,
+            location: Location::default(),
+        }
+    }
+
+    pub fn invalid(reason: &str) -> Self {
+        Error::Invalid {
+            reason: reason.to_string(),
+            location: Location::default(),
+        }
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Error::Missing { location, .. }
+            | Error::InvalidNumber { location, .. }
+            | Error::InvalidCharacter { location, .. }
+            | Error::Invalid { location, .. } => Some(location),
+            Error::Input(_) | Error::Unsolved(_) => None,
+        }
+    }
+
+    fn location_mut(&mut self) -> Option<&mut Location> {
+        match self {
+            Error::Missing { location, .. }
+            | Error::InvalidNumber { location, .. }
+            | Error::InvalidCharacter { location, .. }
+            | Error::Invalid { location, .. } => Some(location),
+            Error::Input(_) | Error::Unsolved(_) => None,
+        }
+    }
+
+    // sets the line, unless a more specific parser already did so
+    pub fn at_line(mut self, line: usize) -> Self {
+        if let Some(location) = self.location_mut() {
+            location.line.get_or_insert(line);
+        }
+        self
+    }
+
+    // sets the column, unless a more specific parser already did so
+    pub fn at_column(mut self, column: usize) -> Self {
+        if let Some(location) = self.location_mut() {
+            location.column.get_or_insert(column);
+        }
+        self
+    }
+
+    // sets the column to where `part` starts within `line`
+    pub fn at_column_of(self, line: &str, part: &str) -> Self {
+        match column_of(line, part) {
+            Some(column) => self.at_column(column),
+            None => self,
+        }
+    }
+
+    // shifts a line relative to a sub-section of the input to be relative to the whole input
+    pub fn offset_line(mut self, lines: usize) -> Self {
+        if let Some(Location {
+            line: Some(line), ..
+        }) = self.location_mut()
+        {
+            *line += lines;
+        }
+        self
+    }
+
+    // shifts a column relative to a sub-slice of a line to be relative to the whole line
+    pub fn offset_column(mut self, columns: usize) -> Self {
+        if let Some(Location {
+            column: Some(column),
+            ..
+        }) = self.location_mut()
+        {
+            *column += columns;
+        }
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing { expected, location } => {
+                write!(f, "Expected {}{}", expected, location)
+            }
+            Error::InvalidNumber { value, location } => {
+                write!(f, "Failed to parse {:?} into a number{}", value, location)
+            }
+            Error::InvalidCharacter {
+                character,
+                location,
+            } => write!(f, "Unexpected character {:?}{}", character, location),
+            Error::Invalid { reason, location } => write!(f, "{}{}", reason, location),
+            Error::Input(reason) => write!(f, "{}", reason),
+            Error::Unsolved(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// 1-based column of `part` when it is a sub-slice of `line`
+pub fn column_of(line: &str, part: &str) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(line.as_ptr() as usize)?;
+    if offset + part.len() <= line.len() {
+        Some(offset + 1)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn error_displays_location() {
+        let error = Error::missing("':' separator").at_line(3).at_column(7);
+        assert_eq!(
+            error.to_string(),
+            "Expected ':' separator at line 3, column 7"
+        );
+
+        let error = Error::invalid_number("x1").at_line(2);
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"x1\" into a number at line 2"
+        );
+
+        let error = Error::invalid_character('?');
+        assert_eq!(error.to_string(), "Unexpected character '?'");
+    }
+
+    #[test]
+    fn error_keeps_most_specific_location() {
+        let error = Error::invalid_character('X')
+            .at_column(4)
+            .offset_column(10)
+            .at_line(1)
+            .offset_line(5)
+            .at_line(99)
+            .at_column(99);
+        assert_eq!(
+            error.location(),
+            Some(&Location {
+                line: Some(6),
+                column: Some(14),
+            })
+        );
+    }
+
+    #[test]
+    fn column_of_finds_sub_slice() {
+        let line = "Card 1: 41 48";
+        let part = line.split_whitespace().nth(3).unwrap();
+        assert_eq!(column_of(line, part), Some(12));
+        let copied_part = String::from(part);
+        assert_eq!(column_of(line, &copied_part), None);
+    }
+}
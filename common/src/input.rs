@@ -0,0 +1,94 @@
+use std::env;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+// overrides the directory holding each day's cached input
+pub const CACHE_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Cache,
+}
+
+impl InputSource {
+    pub fn read(&self, day: u8) -> Result<String> {
+        match self {
+            InputSource::Path(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                match io::stdin().read_to_string(&mut contents) {
+                    Ok(_) => Ok(contents),
+                    Err(error) => Err(Error::Input(format!("Couldn't read from stdin: {}", error))),
+                }
+            }
+            InputSource::Cache => read_path(&cache_path(day)),
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(argument: &str) -> Self {
+        match argument {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+fn read_path(path: &Path) -> Result<String> {
+    match read_to_string(path) {
+        Ok(contents) => Ok(contents),
+        Err(_) => Err(Error::Input(format!(
+            "Couldn't find file: {}",
+            path.display()
+        ))),
+    }
+}
+
+pub fn cache_dir() -> PathBuf {
+    match env::var(CACHE_DIR_VARIABLE) {
+        Ok(dir) => PathBuf::from(dir),
+        // the workspace root, which holds a directory for each day
+        Err(_) => Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+    }
+}
+
+pub fn cache_path(day: u8) -> PathBuf {
+    cache_dir().join(format!("day{}", day)).join("input.txt")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn input_source_parses_from_argument() {
+        assert_eq!(InputSource::from("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from("day9/example.txt"),
+            InputSource::Path(PathBuf::from("day9/example.txt"))
+        );
+    }
+
+    #[test]
+    fn cache_path_points_at_day_directory() {
+        let path = cache_path(9);
+        assert!(path.ends_with("day9/input.txt"));
+    }
+
+    #[test]
+    fn missing_path_returns_error() {
+        let source = InputSource::Path(PathBuf::from("./does-not-exist.txt"));
+        assert_eq!(
+            source.read(9),
+            Err(Error::Input(
+                "Couldn't find file: ./does-not-exist.txt".to_string()
+            ))
+        );
+    }
+}
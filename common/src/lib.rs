@@ -0,0 +1,70 @@
+pub mod error;
+pub mod input;
+
+use error::{Error, Result};
+
+// how a breakdown of an answer is written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Table,
+    Json,
+}
+
+impl TryFrom<&str> for ReportFormat {
+    type Error = String;
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "Format must be either table or json, got {}",
+                value
+            )),
+        }
+    }
+}
+
+pub trait Solution {
+    // day of the advent calendar being solved
+    fn day(&self) -> u8;
+
+    fn part1(&self, input: &str) -> Result<String>;
+
+    fn part2(&self, input: &str) -> Result<String>;
+
+    // a breakdown of how the answer was reached, for days which offer one
+    fn explain_part1(&self, _input: &str, _format: ReportFormat) -> Result<String> {
+        Err(Error::Unsolved(format!(
+            "Day {} part 1 has no explanation",
+            self.day()
+        )))
+    }
+
+    fn explain_part2(&self, _input: &str, _format: ReportFormat) -> Result<String> {
+        Err(Error::Unsolved(format!(
+            "Day {} part 2 has no explanation",
+            self.day()
+        )))
+    }
+
+    // the answer under rules read at runtime, in place of the rules either part plays by,
+    // for days whose rules can be configured
+    fn solve_with_rules(&self, _input: &str, _rules: &str) -> Result<String> {
+        Err(Error::Unsolved(format!(
+            "Day {} has no rules to configure",
+            self.day()
+        )))
+    }
+
+    fn explain_with_rules(
+        &self,
+        _input: &str,
+        _rules: &str,
+        _format: ReportFormat,
+    ) -> Result<String> {
+        Err(Error::Unsolved(format!(
+            "Day {} has no rules to configure",
+            self.day()
+        )))
+    }
+}
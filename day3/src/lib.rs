@@ -0,0 +1,22 @@
+use common::error::Result;
+use common::Solution;
+
+mod part1;
+mod part2;
+pub mod schematic;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1::process(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2::process(input)?.to_string())
+    }
+}
@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use common::error::Result;
+
+use crate::schematic::Schematic;
+
+// the sum of every number next to a symbol
+pub fn process(input: &str) -> Result<u32> {
+    let schematic = Schematic::from_str(input)?;
+
+    Ok(schematic
+        .numbers()
+        .iter()
+        .filter(|span| {
+            schematic
+                .span_neighbours(span)
+                .any(|(_, _, cell)| cell.is_symbol())
+        })
+        .map(|span| span.value)
+        .sum())
+}
+
+#[cfg(test)]
+mod part_1_tests {
+    use super::*;
+
+    #[test]
+    fn process_passes() -> Result<()> {
+        let input = include_str!("./sample.txt");
+        let sum = process(input)?;
+        assert_eq!(sum, 4361_u32);
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common::error::Result;
+
+use crate::schematic::{Cell, Schematic};
+
+// the sum of each gear's ratio, a gear being a '*' next to exactly two numbers
+pub fn process(input: &str) -> Result<u32> {
+    let schematic = Schematic::from_str(input)?;
+    let mut gear_map: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+
+    for span in schematic.numbers() {
+        for (row, column, cell) in schematic.span_neighbours(span) {
+            if cell == Cell::Symbol('*') {
+                gear_map.entry((row, column)).or_default().push(span.value);
+            }
+        }
+    }
+
+    Ok(gear_map
+        .values()
+        .filter(|part_numbers| part_numbers.len() == 2)
+        .map(|part_numbers| part_numbers[0] * part_numbers[1])
+        .sum())
+}
+
+#[cfg(test)]
+mod part_2_tests {
+    use super::*;
+
+    #[test]
+    fn process_passes() -> Result<()> {
+        let input = include_str!("./sample.txt");
+        let sum = process(input)?;
+        assert_eq!(sum, 467835_u32);
+        Ok(())
+    }
+}
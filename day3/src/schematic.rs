@@ -0,0 +1,215 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use common::error::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol(char),
+}
+
+impl Cell {
+    fn new(character: char) -> Self {
+        match character {
+            '.' => Cell::Empty,
+            _ => match character.to_digit(10) {
+                Some(digit) => Cell::Digit(digit),
+                None => Cell::Symbol(character),
+            },
+        }
+    }
+
+    pub fn is_symbol(&self) -> bool {
+        matches!(self, Cell::Symbol(_))
+    }
+}
+
+// a number read left to right along a row, alongside the columns its digits sit in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberSpan {
+    pub value: u32,
+    pub row: usize,
+    pub columns: Range<usize>,
+}
+
+// the engine schematic as a grid of cells, where rows may differ in length
+#[derive(Debug)]
+pub struct Schematic {
+    rows: Vec<Vec<Cell>>,
+    numbers: Vec<NumberSpan>,
+}
+
+impl Schematic {
+    pub fn get(&self, row: usize, column: usize) -> Option<Cell> {
+        self.rows.get(row)?.get(column).copied()
+    }
+
+    // every cell alongside its row & column
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize, Cell)> + '_ {
+        self.rows.iter().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(column, cell)| (row, column, *cell))
+        })
+    }
+
+    pub fn numbers(&self) -> &[NumberSpan] {
+        &self.numbers
+    }
+
+    // the cells surrounding a span of columns within a row, diagonals included
+    fn surrounding(
+        &self,
+        row: usize,
+        columns: Range<usize>,
+    ) -> impl Iterator<Item = (usize, usize, Cell)> + '_ {
+        let rows = row.saturating_sub(1)..row + 2;
+        let around = columns.start.saturating_sub(1)..columns.end + 1;
+        rows.flat_map(move |neighbour_row| {
+            around
+                .clone()
+                .map(move |neighbour_column| (neighbour_row, neighbour_column))
+        })
+        .filter(move |(neighbour_row, neighbour_column)| {
+            *neighbour_row != row || !columns.contains(neighbour_column)
+        })
+        .filter_map(|(neighbour_row, neighbour_column)| {
+            let cell = self.get(neighbour_row, neighbour_column)?;
+            Some((neighbour_row, neighbour_column, cell))
+        })
+    }
+
+    // the 8 cells surrounding a cell, fewer along the edges of the grid
+    pub fn neighbours(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize, Cell)> + '_ {
+        self.surrounding(row, column..column + 1)
+    }
+
+    pub fn span_neighbours(
+        &self,
+        span: &NumberSpan,
+    ) -> impl Iterator<Item = (usize, usize, Cell)> + '_ {
+        self.surrounding(span.row, span.columns.clone())
+    }
+}
+
+// reads each run of digits within a row as a number
+fn tokenize(row: usize, cells: &[Cell]) -> Result<Vec<NumberSpan>> {
+    let mut numbers: Vec<NumberSpan> = vec![];
+    let mut active: Option<NumberSpan> = None;
+
+    for (column, cell) in cells.iter().enumerate() {
+        match (cell, active.as_mut()) {
+            (Cell::Digit(digit), Some(span)) => {
+                span.value = span
+                    .value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(*digit))
+                    .ok_or(
+                        Error::invalid("Number is too large to be a part number")
+                            .at_line(row + 1)
+                            .at_column(span.columns.start + 1),
+                    )?;
+                span.columns.end = column + 1;
+            }
+            (Cell::Digit(digit), None) => {
+                active = Some(NumberSpan {
+                    value: *digit,
+                    row,
+                    columns: column..column + 1,
+                })
+            }
+            (_, _) => numbers.extend(active.take()),
+        }
+    }
+
+    numbers.extend(active);
+    Ok(numbers)
+}
+
+impl FromStr for Schematic {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self> {
+        let rows: Vec<Vec<Cell>> = input
+            .lines()
+            .map(|line| line.chars().map(Cell::new).collect())
+            .collect();
+
+        let mut numbers: Vec<NumberSpan> = vec![];
+        for (row, cells) in rows.iter().enumerate() {
+            numbers.extend(tokenize(row, cells)?);
+        }
+
+        Ok(Self { rows, numbers })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numbers_are_tokenized_with_their_spans() -> Result<()> {
+        let schematic = Schematic::from_str(include_str!("./sample-snippet.txt"))?;
+        let numbers: Vec<(u32, usize, Range<usize>)> = schematic
+            .numbers()
+            .iter()
+            .map(|span| (span.value, span.row, span.columns.clone()))
+            .collect();
+        assert_eq!(
+            numbers,
+            vec![
+                (467, 0, 0..3),
+                (114, 0, 5..8),
+                (35, 2, 2..4),
+                (633, 2, 6..9)
+            ]
+        );
+
+        let schematic = Schematic::from_str("12.3\n45")?;
+        assert_eq!(
+            schematic.numbers().last(),
+            Some(&NumberSpan {
+                value: 45,
+                row: 1,
+                columns: 0..2,
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn neighbours_include_diagonals() -> Result<()> {
+        let schematic = Schematic::from_str(include_str!("./sample-snippet.txt"))?;
+        assert_eq!(schematic.neighbours(0, 0).count(), 3);
+        assert_eq!(schematic.neighbours(1, 3).count(), 8);
+        assert_eq!(schematic.get(1, 3), Some(Cell::Symbol('*')));
+        assert!(schematic
+            .neighbours(1, 3)
+            .any(|(row, column, cell)| (row, column, cell) == (0, 2, Cell::Digit(7))));
+
+        let span = &schematic.numbers()[2];
+        let symbols: Vec<(usize, usize, Cell)> = schematic
+            .span_neighbours(span)
+            .filter(|(_, _, cell)| cell.is_symbol())
+            .collect();
+        assert_eq!(symbols, vec![(1, 3, Cell::Symbol('*'))]);
+        assert_eq!(schematic.span_neighbours(span).count(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn oversized_numbers_are_reported() {
+        let error = Schematic::from_str("...\n..99999999999").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Number is too large to be a part number at line 2, column 3"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use common::error::Result;
+use common::Solution;
+
+pub mod scratchcard;
+
+use scratchcard::pile::Pile;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        let scratchcard_pile = Pile::parse(input)?;
+        Ok(scratchcard_pile.calculate_points().to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let scratchcard_pile = Pile::parse(input)?;
+        Ok(scratchcard_pile.calculate_scratchcard_count().to_string())
+    }
+}
@@ -1,3 +1,5 @@
+use common::error::{Error, Result};
+
 #[derive(Debug, Clone)]
 pub struct Card {
     pub id: u32,
@@ -6,49 +8,53 @@ pub struct Card {
 }
 
 impl Card {
-    fn split_into_id_and_number_parts(input: &str) -> Result<(&str, &str), &str> {
+    fn split_into_id_and_number_parts(input: &str) -> Result<(&str, &str)> {
         let (id_part, numbers_part) = input
             .split_once(':')
-            .expect("Failed to split game id part from input");
+            .ok_or(Error::missing("':' between card id and numbers"))?;
         Ok((id_part.trim(), numbers_part.trim()))
     }
 
-    fn split_numbers_part(numbers_part: &str) -> Result<(&str, &str), &str> {
-        let (winning_numbers, player_numbers) = numbers_part
-            .split_once('|')
-            .expect("Failed to split winning numbers from player numbers");
+    fn split_numbers_part<'a>(input: &str, numbers_part: &'a str) -> Result<(&'a str, &'a str)> {
+        let (winning_numbers, player_numbers) = numbers_part.split_once('|').ok_or(
+            Error::missing("'|' between winning and player numbers")
+                .at_column_of(input, numbers_part),
+        )?;
         Ok((winning_numbers, player_numbers))
     }
 
-    fn parse_numbers(numbers: &str) -> Result<Vec<u32>, &str> {
-        let numbers: Vec<u32> = numbers
-            .trim()
+    fn parse_numbers(input: &str, numbers: &str) -> Result<Vec<u32>> {
+        numbers
             .split_whitespace()
-            .map(|number| number.parse().expect("Failed to parse number"))
-            .collect();
-        Ok(numbers)
+            .map(|number| {
+                number
+                    .parse()
+                    .map_err(|_| Error::invalid_number(number).at_column_of(input, number))
+            })
+            .collect()
     }
 
-    fn parse_numbers_from_part(numbers_part: &str) -> Result<(Vec<u32>, Vec<u32>), &str> {
-        let (winning_numbers, player_numbers) = Self::split_numbers_part(numbers_part)?;
-        let winning_numbers = Self::parse_numbers(winning_numbers)?;
-        let player_numbers = Self::parse_numbers(player_numbers)?;
+    fn parse_numbers_from_part(input: &str, numbers_part: &str) -> Result<(Vec<u32>, Vec<u32>)> {
+        let (winning_numbers, player_numbers) = Self::split_numbers_part(input, numbers_part)?;
+        let winning_numbers = Self::parse_numbers(input, winning_numbers)?;
+        let player_numbers = Self::parse_numbers(input, player_numbers)?;
         Ok((winning_numbers, player_numbers))
     }
 
-    fn parse_id_from_part(id_part: &str) -> Result<u32, &str> {
+    fn parse_id_from_part(input: &str, id_part: &str) -> Result<u32> {
         let card_id = id_part
             .split_whitespace()
             .nth(1)
-            .expect("Failed to split id from card id part");
-        let card_id: u32 = card_id.parse().expect("Failed to parse id");
-        Ok(card_id)
+            .ok_or(Error::missing("card id").at_column_of(input, id_part))?;
+        card_id
+            .parse()
+            .map_err(|_| Error::invalid_number(card_id).at_column_of(input, card_id))
     }
 
-    pub fn parse(input: &str) -> Result<Self, &str> {
+    pub fn parse(input: &str) -> Result<Self> {
         let (id_part, numbers_part) = Self::split_into_id_and_number_parts(input)?;
-        let id = Self::parse_id_from_part(id_part)?;
-        let (winning_numbers, player_numbers) = Self::parse_numbers_from_part(numbers_part)?;
+        let id = Self::parse_id_from_part(input, id_part)?;
+        let (winning_numbers, player_numbers) = Self::parse_numbers_from_part(input, numbers_part)?;
 
         Ok(Self {
             id,
@@ -60,15 +66,15 @@ impl Card {
     pub fn calculate_points(&self) -> u32 {
         self.winning_numbers
             .iter()
-            .filter(|&winning_number| self.player_numbers.contains(&winning_number))
+            .filter(|&winning_number| self.player_numbers.contains(winning_number))
             .fold(0, |acc, _| if acc == 0 { acc + 1 } else { acc * 2 })
     }
 
     pub fn calculate_intersecting_number_count(&self) -> u32 {
         self.winning_numbers
             .iter()
-            .filter(|&winning_number| self.player_numbers.contains(&winning_number))
-            .map(|winning_number| winning_number.clone())
+            .filter(|&winning_number| self.player_numbers.contains(winning_number))
+            .copied()
             .collect::<Vec<u32>>()
             .len() as u32
     }
@@ -95,6 +101,19 @@ mod test {
         }
     }
 
+    #[test]
+    fn card_parse_reports_column_of_invalid_number() {
+        let input = "Card 1: 41 4x 83 86 17 | 83 86  6 31 17  9 48 53";
+        let error = Card::parse(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"4x\" into a number at column 12"
+        );
+
+        let input = "Card 1: 41 48 83 86 17";
+        assert!(Card::parse(input).is_err());
+    }
+
     #[test]
     fn card_calculates_intersecting_number_count_successfully() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use common::error::Result;
+
 use super::card::Card;
 
 #[derive(Debug, Clone)]
@@ -14,14 +16,17 @@ impl Pile {
         input.lines().collect()
     }
 
-    fn parse_scratchcard_lines(scratchcard_lines: Vec<&str>) -> Result<Vec<Card>, &str> {
+    fn parse_scratchcard_lines(scratchcard_lines: Vec<&str>) -> Result<Vec<Card>> {
         scratchcard_lines
             .iter()
-            .map(|scratchcard_line| Card::parse(scratchcard_line))
+            .enumerate()
+            .map(|(line_index, scratchcard_line)| {
+                Card::parse(scratchcard_line).map_err(|error| error.at_line(line_index + 1))
+            })
             .collect()
     }
 
-    pub fn parse(input: &str) -> Result<Self, &str> {
+    pub fn parse(input: &str) -> Result<Self> {
         let scratchcard_lines = Self::split_scratchcard_lines(input);
         let scratchcards = Self::parse_scratchcard_lines(scratchcard_lines)?;
 
@@ -46,7 +51,7 @@ impl Pile {
             // get saved count for id if one exists
             if let Some(copy_count) = scratchcard_count_map.get(&scratchcard.id) {
                 // add saved count to count
-                count = count + copy_count;
+                count += copy_count;
             }
 
             // set count for current scratchcard
@@ -67,15 +72,11 @@ impl Pile {
                     }
                 }
 
-                count = count - 1;
+                count -= 1;
             }
         }
 
-        dbg!(&scratchcard_count_map);
-
-        scratchcard_count_map
-            .values()
-            .fold(0, |acc, count| acc + count)
+        scratchcard_count_map.values().sum()
     }
 }
 
@@ -85,17 +86,27 @@ mod test {
 
     #[test]
     fn pile_parses_successfully() {
-        let input = include!("../sample.txt");
+        let input = include_str!("../sample.txt");
         let scratchcard_pile = Pile::parse(input);
-        assert_eq!(
-            true,
+        assert!(
             scratchcard_pile.is_ok_and(|scratchcard_pile| scratchcard_pile.scratchcards.len() == 6)
         );
     }
 
+    #[test]
+    fn pile_parse_reports_line_of_invalid_card() {
+        let input = "Card 1: 41 48 | 83 86
+Card 2 13 32 | 61 30";
+        let error = Pile::parse(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected ':' between card id and numbers at line 2"
+        );
+    }
+
     #[test]
     fn pile_calculates_points_successfully() {
-        let input = include!("../sample.txt");
+        let input = include_str!("../sample.txt");
         let scratchcard_pile = Pile::parse(input);
         match scratchcard_pile {
             Ok(scratchcard_pile) => {
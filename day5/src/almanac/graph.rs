@@ -0,0 +1,172 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::error::{Error, Result};
+
+use crate::almanac::map::Map;
+use crate::almanac::range::{Id, SeedRange};
+
+// maps keyed by the category they convert from, so they can be chained in any order
+#[derive(Debug, Default)]
+pub struct CategoryGraph {
+    maps: Vec<Map>,
+    maps_by_source_name: HashMap<String, Vec<usize>>,
+}
+
+impl CategoryGraph {
+    pub fn add(&mut self, map: Map) -> Result<()> {
+        let map_indexes = self
+            .maps_by_source_name
+            .entry(map.source_name().to_string())
+            .or_default();
+
+        if map_indexes
+            .iter()
+            .any(|&index| self.maps[index].destination_name() == map.destination_name())
+        {
+            return Err(Error::invalid(&format!(
+                "Duplicate {}-to-{} map",
+                map.source_name(),
+                map.destination_name()
+            )));
+        }
+
+        map_indexes.push(self.maps.len());
+        self.maps.push(map);
+        Ok(())
+    }
+
+    pub fn maps(&self) -> &[Map] {
+        &self.maps
+    }
+
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = self
+            .maps
+            .iter()
+            .flat_map(|map| [map.source_name(), map.destination_name()])
+            .collect();
+        categories.sort();
+        categories.dedup();
+        categories
+    }
+
+    // shortest chain of maps leading from one category to another
+    pub fn path(&self, source_name: &str, destination_name: &str) -> Result<Vec<&Map>> {
+        let mut previous_map_indexes: HashMap<&str, Option<usize>> =
+            HashMap::from([(source_name, None)]);
+        let mut queue: VecDeque<&str> = VecDeque::from([source_name]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == destination_name {
+                break;
+            }
+
+            for &index in self.maps_by_source_name.get(category).into_iter().flatten() {
+                let next_category = self.maps[index].destination_name();
+                if !previous_map_indexes.contains_key(next_category) {
+                    previous_map_indexes.insert(next_category, Some(index));
+                    queue.push_back(next_category);
+                }
+            }
+        }
+
+        if !previous_map_indexes.contains_key(destination_name) {
+            return Err(Error::invalid(&format!(
+                "No maps lead from {} to {}",
+                source_name, destination_name
+            )));
+        }
+
+        let mut path: Vec<&Map> = vec![];
+        let mut category = destination_name;
+        while let Some(Some(index)) = previous_map_indexes.get(category) {
+            let map = &self.maps[*index];
+            path.push(map);
+            category = map.source_name();
+        }
+        path.reverse();
+        Ok(path)
+    }
+
+    // every map along the path folded into a single map
+    pub fn collapse(&self, source_name: &str, destination_name: &str) -> Result<Map> {
+        let path = self.path(source_name, destination_name)?;
+        Ok(match path.split_first() {
+            Some((first_map, other_maps)) => other_maps
+                .iter()
+                .fold(first_map.normalise(), |collapsed, map| collapsed.then(map)),
+            None => Map::identity(source_name, destination_name),
+        })
+    }
+
+    pub fn convert(&self, source_name: &str, destination_name: &str, id: Id) -> Result<Id> {
+        Ok(self
+            .path(source_name, destination_name)?
+            .iter()
+            .fold(id, |id, map| map.get_destination_id_by_source_id(id)))
+    }
+
+    pub fn convert_range_back(
+        &self,
+        source_name: &str,
+        destination_name: &str,
+        destination_range: &SeedRange,
+    ) -> Result<Vec<SeedRange>> {
+        Ok(self.path(source_name, destination_name)?.iter().rev().fold(
+            vec![*destination_range],
+            |destination_ranges, map| {
+                destination_ranges
+                    .iter()
+                    .flat_map(|destination_range| {
+                        map.get_source_ranges_by_destination_range(destination_range)
+                    })
+                    .collect()
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn graph_from(inputs: &[&str]) -> Result<CategoryGraph> {
+        let mut graph = CategoryGraph::default();
+        for input in inputs {
+            graph.add(Map::parse(input)?)?;
+        }
+        Ok(graph)
+    }
+
+    #[test]
+    fn graph_finds_path_regardless_of_order() -> Result<()> {
+        let graph = graph_from(&[
+            "soil-to-fertilizer map:\n0 15 37",
+            "fertilizer-to-water map:\n49 53 8",
+            "seed-to-soil map:\n50 98 2",
+        ])?;
+        let path: Vec<&str> = graph
+            .path("seed", "water")?
+            .iter()
+            .map(|map| map.destination_name())
+            .collect();
+        assert_eq!(path, vec!["soil", "fertilizer", "water"]);
+        assert!(graph.path("seed", "seed")?.is_empty());
+        assert_eq!(
+            graph.path("water", "seed").unwrap_err().to_string(),
+            "No maps lead from water to seed"
+        );
+        assert_eq!(
+            graph.categories(),
+            vec!["fertilizer", "seed", "soil", "water"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn graph_rejects_duplicate_maps() {
+        let error =
+            graph_from(&["seed-to-soil map:\n50 98 2", "seed-to-soil map:\n52 50 48"]).unwrap_err();
+        assert_eq!(error.to_string(), "Duplicate seed-to-soil map");
+    }
+}
@@ -0,0 +1,455 @@
+use crate::almanac::range::{Id, SeedRange, SourceRange};
+use std::collections::VecDeque;
+use std::fmt;
+
+use common::error::{Error, Result};
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    source_name: String,
+    destination_name: String,
+    source_ranges: Vec<SourceRange>,
+    // sorted, non-overlapping source ranges covering every id
+    normalised: bool,
+}
+
+impl Map {
+    pub fn identity(source_name: &str, destination_name: &str) -> Self {
+        Self {
+            source_name: source_name.to_string(),
+            destination_name: destination_name.to_string(),
+            source_ranges: vec![SourceRange::new(0, Id::MAX, 0)],
+            normalised: true,
+        }
+    }
+
+    fn parse_names_from_description(line: &str) -> Result<(String, String)> {
+        let type_to_type_part = line
+            .split_whitespace()
+            .next()
+            .ok_or(Error::missing("map description"))?;
+
+        let (source_type_name, destination_type_name) =
+            type_to_type_part.split_once("-to-").ok_or(
+                Error::missing("'-to-' between map names").at_column_of(line, type_to_type_part),
+            )?;
+
+        Ok((
+            String::from(source_type_name),
+            String::from(destination_type_name),
+        ))
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut lines: VecDeque<&str> = input.lines().collect();
+        let description = lines.pop_front().ok_or(Error::missing("map description"))?;
+
+        let (source_name, destination_name) =
+            Self::parse_names_from_description(description).map_err(|error| error.at_line(1))?;
+
+        let mut source_ranges: Vec<SourceRange> = vec![];
+
+        for (line_index, source_range_line) in lines.iter().enumerate() {
+            let source_range = SourceRange::parse(source_range_line)
+                .map_err(|error| error.at_line(line_index + 2))?;
+            source_ranges.push(source_range);
+        }
+
+        Ok(Self {
+            source_name,
+            destination_name,
+            source_ranges,
+            normalised: false,
+        })
+    }
+
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    pub fn destination_name(&self) -> &str {
+        &self.destination_name
+    }
+
+    pub fn source_ranges(&self) -> &[SourceRange] {
+        &self.source_ranges
+    }
+
+    // joins neighbouring sorted source ranges whose destination ranges also neighbour
+    fn merge_adjacent(source_ranges: Vec<SourceRange>) -> Vec<SourceRange> {
+        let mut merged: Vec<SourceRange> = vec![];
+
+        for source_range in source_ranges {
+            match merged.last_mut() {
+                Some(previous)
+                    if previous.end.checked_add(1) == Some(source_range.start)
+                        && previous.destination_end().checked_add(1)
+                            == Some(source_range.destination_start) =>
+                {
+                    previous.end = source_range.end;
+                }
+                _ => merged.push(source_range),
+            }
+        }
+
+        merged
+    }
+
+    pub fn normalise(&self) -> Map {
+        if self.normalised {
+            return self.clone();
+        }
+
+        let mut source_ranges: Vec<SourceRange> = vec![];
+
+        // earlier source ranges take precedence over later ones they overlap with
+        for source_range in self.source_ranges.iter() {
+            let mut uncovered_ranges: Vec<SeedRange> = vec![source_range.source_range()];
+            for covered_range in source_ranges.iter() {
+                uncovered_ranges = uncovered_ranges
+                    .iter()
+                    .flat_map(|uncovered_range| {
+                        uncovered_range.subtract(&covered_range.source_range())
+                    })
+                    .collect();
+            }
+
+            for uncovered_range in uncovered_ranges {
+                source_ranges.push(SourceRange::new(
+                    uncovered_range.start,
+                    uncovered_range.end,
+                    source_range.convert_to_destination(uncovered_range.start),
+                ));
+            }
+        }
+
+        // fill the gaps with ranges mapping to identical destination ids
+        let mut gaps: Vec<SeedRange> = vec![SeedRange::new(0, Id::MAX)];
+        for source_range in source_ranges.iter() {
+            gaps = gaps
+                .iter()
+                .flat_map(|gap| gap.subtract(&source_range.source_range()))
+                .collect();
+        }
+        source_ranges.extend(
+            gaps.iter()
+                .map(|gap| SourceRange::new(gap.start, gap.end, gap.start)),
+        );
+
+        source_ranges.sort_by_key(|source_range| source_range.start);
+
+        Map {
+            source_name: self.source_name.clone(),
+            destination_name: self.destination_name.clone(),
+            source_ranges: Self::merge_adjacent(source_ranges),
+            normalised: true,
+        }
+    }
+
+    // composes this map with the next, mapping from this map's source to the next map's destination
+    pub fn then(&self, next: &Map) -> Map {
+        let next = next.normalise();
+        let mut source_ranges: Vec<SourceRange> = vec![];
+
+        for source_range in self.normalise().source_ranges.iter() {
+            let destination_range = source_range.destination_range();
+
+            for next_source_range in next.source_ranges.iter() {
+                if let Some(overlap) =
+                    destination_range.intersect(&next_source_range.source_range())
+                {
+                    let composed_range = source_range.convert_range_to_source(&overlap);
+                    source_ranges.push(SourceRange::new(
+                        composed_range.start,
+                        composed_range.end,
+                        next_source_range.convert_to_destination(overlap.start),
+                    ));
+                }
+            }
+        }
+
+        source_ranges.sort_by_key(|source_range| source_range.start);
+
+        Map {
+            source_name: self.source_name.clone(),
+            destination_name: next.destination_name.clone(),
+            source_ranges: Self::merge_adjacent(source_ranges),
+            normalised: true,
+        }
+    }
+
+    pub fn get_destination_id_by_source_id(&self, source_id: Id) -> Id {
+        if self.normalised {
+            let index = self
+                .source_ranges
+                .partition_point(|source_range| source_range.end < source_id);
+            if let Some(source_range) = self.source_ranges.get(index) {
+                return source_range.convert_to_destination(source_id);
+            }
+        }
+
+        let mut destination_id = source_id;
+
+        for source_range in self.source_ranges.iter() {
+            if source_range.contains(source_id) {
+                destination_id = source_range.convert_to_destination(source_id);
+                break;
+            }
+        }
+
+        destination_id
+    }
+
+    pub fn get_destination_ranges_by_source_range(&self, range: &SeedRange) -> Vec<SeedRange> {
+        let mut destination_ranges: Vec<SeedRange> = vec![];
+        let mut unmapped_ranges: Vec<SeedRange> = vec![*range];
+
+        for source_range in self.source_ranges.iter() {
+            let mut remaining_ranges: Vec<SeedRange> = vec![];
+
+            for unmapped_range in unmapped_ranges.iter() {
+                let (overlap, outside) = source_range.split(unmapped_range);
+                if let Some(overlap) = overlap {
+                    destination_ranges.push(source_range.convert_range_to_destination(&overlap));
+                }
+                remaining_ranges.extend(outside);
+            }
+
+            unmapped_ranges = remaining_ranges;
+        }
+
+        // unmapped ids map to identical destination ids
+        destination_ranges.extend(unmapped_ranges);
+        destination_ranges
+    }
+
+    pub fn get_source_ids_by_destination_id(&self, destination_id: Id) -> Vec<Id> {
+        let mut source_ids: Vec<Id> = self
+            .get_source_ranges_by_destination_range(&SeedRange::single(destination_id))
+            .iter()
+            .map(|source_range| source_range.start)
+            .collect();
+        source_ids.sort();
+        source_ids
+    }
+
+    pub fn get_source_ranges_by_destination_range(&self, range: &SeedRange) -> Vec<SeedRange> {
+        let mut source_ranges: Vec<SeedRange> = vec![];
+
+        // several source ranges may map onto the same destination ids
+        for source_range in self.source_ranges.iter() {
+            if let Some(overlap) = range.intersect(&source_range.destination_range()) {
+                source_ranges.push(source_range.convert_range_to_source(&overlap));
+            }
+        }
+
+        // destination ids which aren't a mapped source id come from the identical source id
+        let mut identical_ranges: Vec<SeedRange> = vec![*range];
+        for source_range in self.source_ranges.iter() {
+            identical_ranges = identical_ranges
+                .iter()
+                .flat_map(|identical_range| identical_range.subtract(&source_range.source_range()))
+                .collect();
+        }
+
+        source_ranges.extend(identical_ranges);
+        source_ranges
+    }
+}
+
+// normalised maps leave out the ranges mapping ids to themselves, as they can't overlap any
+// other range & unmapped ids map to themselves anyway, while one spanning every id is too long
+// to parse back
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{} map:", self.source_name, self.destination_name)?;
+        for source_range in self.source_ranges.iter().filter(|source_range| {
+            !self.normalised || source_range.destination_start != source_range.start
+        }) {
+            write!(
+                f,
+                "\n{} {} {}",
+                source_range.destination_start,
+                source_range.start,
+                source_range.range_length()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map_returns_correct_destination_id() {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48";
+        let map = Map::parse(input);
+        match map {
+            Ok(map) => {
+                assert_eq!(map.get_destination_id_by_source_id(56), 58);
+                assert_eq!(map.get_destination_id_by_source_id(99), 51);
+            }
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    #[test]
+    fn map_returns_correct_destination_ranges() -> Result<()> {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48";
+        let map = Map::parse(input)?;
+        let mut destination_ranges =
+            map.get_destination_ranges_by_source_range(&SeedRange::new(45, 99));
+        destination_ranges.sort_by_key(|range| range.start);
+        assert_eq!(
+            destination_ranges,
+            vec![
+                SeedRange::new(45, 49),
+                SeedRange::new(50, 51),
+                SeedRange::new(52, 99),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn map_returns_correct_source_ids() -> Result<()> {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48";
+        let map = Map::parse(input)?;
+        assert_eq!(map.get_source_ids_by_destination_id(58), vec![56]);
+        assert_eq!(map.get_source_ids_by_destination_id(51), vec![99]);
+        assert_eq!(map.get_source_ids_by_destination_id(99), vec![97]);
+        assert_eq!(map.get_source_ids_by_destination_id(98), vec![96]);
+        assert_eq!(map.get_source_ids_by_destination_id(10), vec![10]);
+        Ok(())
+    }
+
+    #[test]
+    fn map_returns_correct_source_ranges() -> Result<()> {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48";
+        let map = Map::parse(input)?;
+        let mut source_ranges = map.get_source_ranges_by_destination_range(&SeedRange::new(45, 55));
+        source_ranges.sort_by_key(|range| range.start);
+        assert_eq!(
+            source_ranges,
+            vec![
+                SeedRange::new(45, 49),
+                SeedRange::new(50, 53),
+                SeedRange::new(98, 99),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn map_normalises_into_sorted_ranges_covering_every_id() -> Result<()> {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48
+0 96 4";
+        let map = Map::parse(input)?.normalise();
+        assert_eq!(
+            map.source_ranges(),
+            &[
+                SourceRange::new(0, 49, 0),
+                SourceRange::new(50, 97, 52),
+                SourceRange::new(98, 99, 50),
+                SourceRange::new(100, Id::MAX, 100),
+            ]
+        );
+        assert_eq!(map.get_destination_id_by_source_id(56), 58);
+        assert_eq!(map.get_destination_id_by_source_id(99), 51);
+        assert_eq!(map.get_destination_id_by_source_id(10), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn maps_compose_into_a_single_map() -> Result<()> {
+        let seed_to_soil = Map::parse(
+            "seed-to-soil map:
+50 98 2
+52 50 48",
+        )?;
+        let soil_to_fertilizer = Map::parse(
+            "soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15",
+        )?;
+        let seed_to_fertilizer = seed_to_soil.then(&soil_to_fertilizer);
+        assert_eq!(seed_to_fertilizer.source_name(), "seed");
+        assert_eq!(seed_to_fertilizer.destination_name(), "fertilizer");
+
+        for seed_id in 0..200 {
+            let soil_id = seed_to_soil.get_destination_id_by_source_id(seed_id);
+            let fertilizer_id = soil_to_fertilizer.get_destination_id_by_source_id(soil_id);
+            assert_eq!(
+                seed_to_fertilizer.get_destination_id_by_source_id(seed_id),
+                fertilizer_id
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn map_displays_in_almanac_format() -> Result<()> {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48";
+        let map = Map::parse(input)?;
+        assert_eq!(map.to_string(), input);
+        assert_eq!(
+            map.normalise().to_string(),
+            "seed-to-soil map:
+52 50 48
+50 98 2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn normalised_maps_parse_back_from_their_display() -> Result<()> {
+        let map = Map::parse(
+            "seed-to-soil map:
+50 98 2
+52 50 48
+0 96 4
+10 10 5",
+        )?
+        .normalise();
+        let parsed = Map::parse(&map.to_string())?.normalise();
+        assert_eq!(parsed.source_ranges(), map.source_ranges());
+
+        let identity = Map::identity("seed", "soil");
+        assert_eq!(identity.to_string(), "seed-to-soil map:");
+        let parsed = Map::parse(&identity.to_string())?.normalise();
+        assert_eq!(parsed.source_ranges(), identity.source_ranges());
+        Ok(())
+    }
+
+    #[test]
+    fn map_parses_successfully() {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 48";
+        let map = Map::parse(input);
+        dbg!(&map);
+        match map {
+            Ok(map) => {
+                assert_eq!(map.source_name, "seed");
+                assert_eq!(map.destination_name, "soil");
+                assert_eq!(map.source_ranges.len(), 2);
+            }
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
@@ -0,0 +1,490 @@
+use std::collections::VecDeque;
+
+use common::error::{Error, Result};
+
+pub mod graph;
+pub mod map;
+pub mod range;
+
+use graph::CategoryGraph;
+use range::{Id, SeedRange};
+
+const SEED_CATEGORY: &str = "seed";
+const LOCATION_CATEGORY: &str = "location";
+
+pub enum SeedParseMode {
+    Simple,
+    Range,
+}
+
+pub struct AlmanacOptions {
+    pub seed_parse_mode: SeedParseMode,
+}
+
+// a blank line separated section of input, alongside the line it starts at
+type Section<'a> = (usize, &'a str);
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<SeedRange>,
+    categories: CategoryGraph,
+    // every mapper from seed to location folded into a single map, collapsed once when parsing
+    seed_to_location: map::Map,
+}
+
+impl Almanac {
+    // splits input into blank line separated sections
+    fn split_sections(input: &str) -> Vec<Section<'_>> {
+        let mut sections: Vec<Section> = vec![];
+        let mut section: Option<(usize, usize, usize)> = None;
+        let mut offset = 0;
+
+        for (line_index, line) in input.split('\n').enumerate() {
+            let line_end = offset + line.len();
+            if line.trim().is_empty() {
+                if let Some((start_line, start, end)) = section.take() {
+                    sections.push((start_line, &input[start..end]));
+                }
+            } else {
+                match section {
+                    Some((_, _, ref mut end)) => *end = line_end,
+                    None => section = Some((line_index + 1, offset, line_end)),
+                }
+            }
+            offset = line_end + 1;
+        }
+
+        if let Some((start_line, start, end)) = section {
+            sections.push((start_line, &input[start..end]));
+        }
+
+        sections
+    }
+
+    fn split_seeds_from_mappers(input: &str) -> Result<(Section<'_>, Vec<Section<'_>>)> {
+        let mut parts: VecDeque<Section> = Self::split_sections(input).into();
+        let seed_str = parts
+            .pop_front()
+            .ok_or(Error::missing("seeds section").at_line(1))?;
+
+        Ok((seed_str, parts.into()))
+    }
+
+    fn parse_seed_ids(seed_str: &str) -> Result<Vec<Id>> {
+        let (_, seed_id_list_str) = seed_str
+            .split_once(':')
+            .ok_or(Error::missing("':' after seeds label"))?;
+
+        seed_id_list_str
+            .split_whitespace()
+            .map(|seed_id| {
+                seed_id
+                    .parse::<Id>()
+                    .map_err(|_| Error::invalid_number(seed_id).at_column_of(seed_str, seed_id))
+            })
+            .collect()
+    }
+
+    fn parse_seed_str_simple(seed_str: &str) -> Result<Vec<SeedRange>> {
+        Ok(Self::parse_seed_ids(seed_str)?
+            .into_iter()
+            .map(SeedRange::single)
+            .collect())
+    }
+
+    fn parse_seed_str_range(seed_str: &str) -> Result<Vec<SeedRange>> {
+        let seed_id_list_str = Self::parse_seed_ids(seed_str)?;
+
+        if seed_id_list_str.len() % 2 != 0 {
+            return Err(Error::invalid(
+                "Seed ranges must be pairs of start id and range",
+            ));
+        }
+
+        let mut seed_ranges: Vec<SeedRange> = vec![];
+
+        for seed_pair in seed_id_list_str.chunks_exact(2) {
+            let (start, range) = (seed_pair[0], seed_pair[1]);
+            if range == 0 {
+                continue;
+            }
+            let end = start
+                .checked_add(range - 1)
+                .ok_or(Error::invalid("Seed range exceeds the maximum seed id"))?;
+            seed_ranges.push(SeedRange::new(start, end));
+        }
+
+        Ok(seed_ranges)
+    }
+
+    fn parse_mappers_str(mappers_str: Vec<Section>) -> Result<CategoryGraph> {
+        let mut categories = CategoryGraph::default();
+
+        for (start_line, mapper_str) in mappers_str.iter() {
+            let mapper =
+                map::Map::parse(mapper_str).map_err(|error| error.offset_line(start_line - 1))?;
+            categories
+                .add(mapper)
+                .map_err(|error| error.at_line(*start_line))?;
+        }
+
+        Ok(categories)
+    }
+
+    // none when the almanac plants no seeds
+    pub fn get_closest_location(&self) -> Option<Id> {
+        self.seeds
+            .iter()
+            .flat_map(|seed_range| self.get_location_ranges_for_seed_range(seed_range))
+            .map(|location_range| location_range.start)
+            .min()
+    }
+
+    // pushes a range of seeds through each mapper, splitting it wherever a mapper's ranges do
+    fn get_location_ranges_for_seed_range(&self, seed_range: &SeedRange) -> Vec<SeedRange> {
+        self.seed_to_location
+            .get_destination_ranges_by_source_range(seed_range)
+    }
+
+    pub fn get_seed_to_location_map(&self) -> &map::Map {
+        &self.seed_to_location
+    }
+
+    pub fn get_seed_ranges_for_location_range(&self, location_range: &SeedRange) -> Vec<SeedRange> {
+        // a path from seed to location is checked for when parsing
+        self.categories
+            .convert_range_back(SEED_CATEGORY, LOCATION_CATEGORY, location_range)
+            .unwrap_or_default()
+    }
+
+    pub fn get_categories(&self) -> &CategoryGraph {
+        &self.categories
+    }
+
+    // converts an id between any two categories linked by a chain of maps
+    pub fn convert(&self, source_name: &str, destination_name: &str, id: Id) -> Result<Id> {
+        self.categories.convert(source_name, destination_name, id)
+    }
+
+    pub fn get_seeds_for_location(&self, location_id: Id) -> Vec<Id> {
+        let mut seed_ids: Vec<Id> = self
+            .get_seed_ranges_for_location_range(&SeedRange::single(location_id))
+            .iter()
+            .map(|seed_range| seed_range.start)
+            .collect();
+        seed_ids.sort();
+        seed_ids
+    }
+
+    // seeds listed in the almanac which land in the given location
+    pub fn get_planted_seeds_for_location(&self, location_id: Id) -> Vec<Id> {
+        self.get_seeds_for_location(location_id)
+            .into_iter()
+            .filter(|seed_id| {
+                self.seeds
+                    .iter()
+                    .any(|seed_range| seed_range.contains(*seed_id))
+            })
+            .collect()
+    }
+
+    fn is_any_seed_planted_in(&self, location_range: &SeedRange) -> bool {
+        self.get_seed_ranges_for_location_range(location_range)
+            .iter()
+            .any(|candidate_range| {
+                self.seeds
+                    .iter()
+                    .any(|seed_range| seed_range.intersect(candidate_range).is_some())
+            })
+    }
+
+    // searches upwards from location 0, halving the locations in question each step
+    // rather than checking each location one by one
+    pub fn get_closest_location_by_location_search(&self) -> Option<Id> {
+        if !self.is_any_seed_planted_in(&SeedRange::new(0, Id::MAX)) {
+            return None;
+        }
+
+        let (mut lowest, mut highest) = (0, Id::MAX);
+        while lowest < highest {
+            let middle = lowest + (highest - lowest) / 2;
+            if self.is_any_seed_planted_in(&SeedRange::new(0, middle)) {
+                highest = middle;
+            } else {
+                lowest = middle + 1;
+            }
+        }
+
+        Some(lowest)
+    }
+
+    pub fn get_location_for_seed(&self, seed_id: Id) -> Id {
+        self.seed_to_location
+            .get_destination_id_by_source_id(seed_id)
+    }
+
+    pub fn parse(input: &str, options: AlmanacOptions) -> Result<Self> {
+        let ((seed_line, seed_str), mappers_str) = Self::split_seeds_from_mappers(input)?;
+        let seeds = match options.seed_parse_mode {
+            SeedParseMode::Simple => Self::parse_seed_str_simple(seed_str),
+            SeedParseMode::Range => Self::parse_seed_str_range(seed_str),
+        }
+        .map_err(|error| error.at_line(seed_line))?;
+        let categories = Self::parse_mappers_str(mappers_str)?;
+        let seed_to_location = categories.collapse(SEED_CATEGORY, LOCATION_CATEGORY)?;
+        Ok(Self {
+            seeds,
+            categories,
+            seed_to_location,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_location_for_seed() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(almanac.get_location_for_seed(79), 82);
+        assert_eq!(almanac.get_location_for_seed(14), 43);
+        assert_eq!(almanac.get_location_for_seed(55), 86);
+        assert_eq!(almanac.get_location_for_seed(13), 35);
+        Ok(())
+    }
+
+    #[test]
+    fn almanac_parses_successfully() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(almanac.seeds.len(), 4);
+        assert_eq!(almanac.categories.maps().len(), 7);
+        Ok(())
+    }
+
+    #[test]
+    fn almanac_parses_seed_ranges() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Range,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(
+            almanac.seeds,
+            vec![SeedRange::new(79, 92), SeedRange::new(55, 67)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn collapsed_map_matches_walking_each_mapper() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        let seed_to_location = almanac.get_seed_to_location_map();
+        assert_eq!(seed_to_location.source_name(), "seed");
+        assert_eq!(seed_to_location.destination_name(), "location");
+
+        for seed_id in 0..200 {
+            let location_id = almanac
+                .categories
+                .maps()
+                .iter()
+                .fold(seed_id, |id, mapper| {
+                    mapper.get_destination_id_by_source_id(id)
+                });
+            assert_eq!(almanac.get_location_for_seed(seed_id), location_id);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn get_seeds_for_location() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(almanac.get_seeds_for_location(82), vec![79]);
+        assert_eq!(almanac.get_seeds_for_location(35), vec![13]);
+        assert_eq!(almanac.get_planted_seeds_for_location(43), vec![14]);
+        assert_eq!(almanac.get_planted_seeds_for_location(0), vec![]);
+
+        for location_id in 0..100 {
+            for seed_id in almanac.get_seeds_for_location(location_id) {
+                assert_eq!(almanac.get_location_for_seed(seed_id), location_id);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn get_closest_location_by_location_search() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(almanac.get_closest_location_by_location_search(), Some(35));
+
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Range,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(almanac.get_closest_location_by_location_search(), Some(46));
+        Ok(())
+    }
+
+    #[test]
+    fn get_closest_location_for_seed_ranges() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Range,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(almanac.get_closest_location(), Some(46));
+        Ok(())
+    }
+
+    #[test]
+    fn almanacs_without_seeds_have_no_closest_location() -> Result<()> {
+        let input = "seeds:
+
+seed-to-location map:
+50 98 2";
+        for seed_parse_mode in [SeedParseMode::Simple, SeedParseMode::Range] {
+            let almanac = Almanac::parse(input, AlmanacOptions { seed_parse_mode })?;
+            assert_eq!(almanac.get_closest_location(), None);
+            assert_eq!(almanac.get_closest_location_by_location_search(), None);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn almanac_parse_reports_line_of_invalid_range() {
+        let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 5O 48";
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let error = Almanac::parse(input, options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"5O\" into a number at line 5, column 4"
+        );
+    }
+
+    #[test]
+    fn almanac_handles_ids_beyond_u32() -> Result<()> {
+        let input = "seeds: 5000000000 10
+
+seed-to-location map:
+10000000000 5000000000 100";
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Range,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        assert_eq!(almanac.get_closest_location(), Some(10000000000));
+        assert_eq!(almanac.get_location_for_seed(5000000005), 10000000005);
+        assert_eq!(
+            almanac.get_closest_location_by_location_search(),
+            Some(10000000000)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn almanac_parse_reports_line_of_range_exceeding_maximum_id() {
+        let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+0 18446744073709551615 2";
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let error = Almanac::parse(input, options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Source range exceeds the maximum id at line 5"
+        );
+    }
+
+    #[test]
+    fn almanac_converts_between_categories_in_any_order() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let almanac = Almanac::parse(input, options)?;
+
+        // the same blocks, last first
+        let (seeds, mappers) = input.split_once("\n\n").unwrap();
+        let mut blocks: Vec<&str> = mappers.split("\n\n").map(str::trim).collect();
+        blocks.reverse();
+        let shuffled_input = format!("{}\n\n{}", seeds, blocks.join("\n\n"));
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let shuffled_almanac = Almanac::parse(&shuffled_input, options)?;
+
+        assert_eq!(shuffled_almanac.get_location_for_seed(79), 82);
+        assert_eq!(shuffled_almanac.get_closest_location(), Some(35));
+        // seed 79 has soil 81 & humidity 78
+        assert_eq!(shuffled_almanac.convert("soil", "humidity", 81)?, 78);
+        assert_eq!(almanac.convert("soil", "humidity", 81)?, 78);
+        for id in 0..100 {
+            assert_eq!(
+                shuffled_almanac.convert("soil", "humidity", id)?,
+                almanac.convert("soil", "humidity", id)?
+            );
+        }
+        assert!(almanac.convert("humidity", "soil", 78).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn almanac_parse_requires_path_from_seed_to_location() {
+        let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+
+water-to-location map:
+52 50 48";
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let error = Almanac::parse(input, options).unwrap_err();
+        assert_eq!(error.to_string(), "No maps lead from seed to location");
+
+        let input = "seeds: 79 14
+
+seed-to-location map:
+50 98 2
+
+seed-to-location map:
+52 50 48";
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let error = Almanac::parse(input, options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Duplicate seed-to-location map at line 6"
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use common::error::{Error, Result};
+
+const DESTINATION_START_INDEX: usize = 0;
+const SOURCE_START_INDEX: usize = 1;
+const RANGE_INDEX: usize = 2;
+
+pub type Id = u64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedRange {
+    pub start: Id,
+    pub end: Id,
+}
+
+impl SeedRange {
+    pub fn new(start: Id, end: Id) -> Self {
+        Self { start, end }
+    }
+
+    pub fn single(id: Id) -> Self {
+        Self { start: id, end: id }
+    }
+
+    pub fn contains(&self, id: Id) -> bool {
+        id >= self.start && id <= self.end
+    }
+
+    pub fn intersect(&self, other: &SeedRange) -> Option<SeedRange> {
+        if self.end < other.start || self.start > other.end {
+            return None;
+        }
+        Some(SeedRange::new(
+            self.start.max(other.start),
+            self.end.min(other.end),
+        ))
+    }
+
+    // parts of this range falling outside of the other range
+    pub fn subtract(&self, other: &SeedRange) -> Vec<SeedRange> {
+        if self.intersect(other).is_none() {
+            return vec![*self];
+        }
+
+        let mut outside: Vec<SeedRange> = vec![];
+        if self.start < other.start {
+            outside.push(SeedRange::new(self.start, other.start - 1));
+        }
+        if self.end > other.end {
+            outside.push(SeedRange::new(other.end + 1, self.end));
+        }
+        outside
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceRange {
+    pub start: Id,
+    pub end: Id,
+    pub destination_start: Id,
+}
+
+impl SourceRange {
+    pub fn new(start: Id, end: Id, destination_start: Id) -> Self {
+        Self {
+            start,
+            end,
+            destination_start,
+        }
+    }
+
+    pub fn range_length(&self) -> u128 {
+        (self.end - self.start) as u128 + 1
+    }
+
+    pub fn destination_end(&self) -> Id {
+        self.destination_start + (self.end - self.start)
+    }
+
+    pub fn destination_difference(&self) -> i128 {
+        self.destination_start as i128 - self.start as i128
+    }
+
+    fn parse_number(input: &str, number: &str) -> Result<Id> {
+        match number.parse::<Id>() {
+            Ok(number) => Ok(number),
+            Err(_) => Err(Error::invalid_number(number).at_column_of(input, number)),
+        }
+    }
+
+    fn get_numbers(input: &str) -> Result<(Id, Id, Id)> {
+        let numbers: Vec<&str> = input.split_whitespace().collect();
+        let destination_start_id = Self::parse_number(
+            input,
+            numbers
+                .get(DESTINATION_START_INDEX)
+                .ok_or(Error::missing("destination start id"))?,
+        )?;
+
+        let source_start_id = Self::parse_number(
+            input,
+            numbers
+                .get(SOURCE_START_INDEX)
+                .ok_or(Error::missing("source start id"))?,
+        )?;
+
+        let range = Self::parse_number(
+            input,
+            numbers
+                .get(RANGE_INDEX)
+                .ok_or(Error::missing("range length"))?,
+        )?;
+
+        Ok((destination_start_id, source_start_id, range))
+    }
+
+    // expects a source id within this range, which parsing guarantees has a destination id
+    pub fn convert_to_destination(&self, source_id: Id) -> Id {
+        debug_assert!(self.contains(source_id));
+        self.destination_start + (source_id - self.start)
+    }
+
+    pub fn contains(&self, source_id: Id) -> bool {
+        source_id >= self.start && source_id <= self.end
+    }
+
+    // splits a range into the part overlapping this source range & the parts either side of it
+    pub fn split(&self, range: &SeedRange) -> (Option<SeedRange>, Vec<SeedRange>) {
+        let source = self.source_range();
+        (range.intersect(&source), range.subtract(&source))
+    }
+
+    pub fn source_range(&self) -> SeedRange {
+        SeedRange::new(self.start, self.end)
+    }
+
+    pub fn destination_range(&self) -> SeedRange {
+        SeedRange::new(self.destination_start, self.destination_end())
+    }
+
+    // expects a destination id within this range's destination range
+    pub fn convert_to_source(&self, destination_id: Id) -> Id {
+        debug_assert!(self.destination_range().contains(destination_id));
+        self.start + (destination_id - self.destination_start)
+    }
+
+    pub fn convert_range_to_source(&self, range: &SeedRange) -> SeedRange {
+        SeedRange::new(
+            self.convert_to_source(range.start),
+            self.convert_to_source(range.end),
+        )
+    }
+
+    pub fn convert_range_to_destination(&self, range: &SeedRange) -> SeedRange {
+        SeedRange::new(
+            self.convert_to_destination(range.start),
+            self.convert_to_destination(range.end),
+        )
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let (destination_start_id, source_start_id, range) = Self::get_numbers(input)?;
+
+        let last_offset = range
+            .checked_sub(1)
+            .ok_or(Error::invalid("Source range must be non-empty"))?;
+        let end = source_start_id
+            .checked_add(last_offset)
+            .ok_or(Error::invalid("Source range exceeds the maximum id"))?;
+        destination_start_id
+            .checked_add(last_offset)
+            .ok_or(Error::invalid("Destination range exceeds the maximum id"))?;
+
+        Ok(Self {
+            start: source_start_id,
+            end,
+            destination_start: destination_start_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn source_ranges_contains_passes() -> Result<()> {
+        let input = "50 98 2";
+        let source_range = SourceRange::parse(input)?;
+
+        assert_eq!(source_range.convert_to_destination(99), 51);
+        assert_eq!(source_range.convert_to_destination(98), 50);
+
+        Ok(())
+    }
+
+    #[test]
+    fn source_ranges_destination_conversion_passes() -> Result<()> {
+        let input = "50 98 2";
+        let source_range = SourceRange::parse(input)?;
+
+        assert!(source_range.contains(99));
+        assert!(!source_range.contains(21));
+        assert!(source_range.contains(98));
+
+        Ok(())
+    }
+
+    #[test]
+    fn source_range_splits_overlapping_ranges() -> Result<()> {
+        let source_range = SourceRange::parse("52 50 48")?;
+
+        let (overlap, outside) = source_range.split(&SeedRange::new(40, 60));
+        assert_eq!(overlap, Some(SeedRange::new(50, 60)));
+        assert_eq!(outside, vec![SeedRange::new(40, 49)]);
+
+        let (overlap, outside) = source_range.split(&SeedRange::new(40, 100));
+        assert_eq!(overlap, Some(SeedRange::new(50, 97)));
+        assert_eq!(
+            outside,
+            vec![SeedRange::new(40, 49), SeedRange::new(98, 100)]
+        );
+
+        let (overlap, outside) = source_range.split(&SeedRange::new(0, 10));
+        assert_eq!(overlap, None);
+        assert_eq!(outside, vec![SeedRange::new(0, 10)]);
+
+        assert_eq!(
+            source_range.convert_range_to_destination(&SeedRange::new(50, 60)),
+            SeedRange::new(52, 62)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn source_range_converts_back_to_source() -> Result<()> {
+        let source_range = SourceRange::parse("50 98 2")?;
+
+        assert_eq!(source_range.destination_range(), SeedRange::new(50, 51));
+        assert_eq!(source_range.convert_to_source(51), 99);
+        assert_eq!(
+            source_range.convert_range_to_source(&SeedRange::new(50, 51)),
+            SeedRange::new(98, 99)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn source_range_parses_successfully() {
+        let input = "50 98 2";
+        let source_range = SourceRange::parse(input);
+        match source_range {
+            Ok(source_range) => {
+                assert_eq!(source_range.start, 98);
+                assert_eq!(source_range.end, 99);
+                assert_eq!(source_range.destination_difference(), -48);
+            }
+            Err(error) => panic!("{error}"),
+        }
+
+        let input = "52 50 48";
+        let source_range = SourceRange::parse(input);
+        match source_range {
+            Ok(source_range) => {
+                assert_eq!(source_range.start, 50);
+                assert_eq!(source_range.end, 97);
+                assert_eq!(source_range.destination_difference(), 2);
+            }
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    #[test]
+    fn source_range_parse_rejects_ranges_exceeding_the_maximum_id() {
+        let max = Id::MAX;
+        assert!(SourceRange::parse(&format!("0 {} 1", max)).is_ok());
+
+        let error = SourceRange::parse(&format!("0 {} 2", max)).unwrap_err();
+        assert_eq!(error.to_string(), "Source range exceeds the maximum id");
+
+        let error = SourceRange::parse(&format!("{} 0 2", max)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Destination range exceeds the maximum id"
+        );
+
+        let error = SourceRange::parse("0 0 0").unwrap_err();
+        assert_eq!(error.to_string(), "Source range must be non-empty");
+
+        assert!(SourceRange::parse("0 0 18446744073709551616").is_err());
+    }
+
+    #[test]
+    fn source_range_converts_ids_beyond_u32() -> Result<()> {
+        let source_range = SourceRange::parse("10000000000 5000000000 100")?;
+        assert_eq!(source_range.convert_to_destination(5000000099), 10000000099);
+        assert_eq!(source_range.convert_to_source(10000000000), 5000000000);
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use common::error::{Error, Result};
+use common::Solution;
+
+pub mod almanac;
+
+use almanac::{Almanac, AlmanacOptions, SeedParseMode};
+
+fn get_closest_location(almanac: &Almanac) -> Result<String> {
+    almanac
+        .get_closest_location()
+        .map(|location| location.to_string())
+        .ok_or(Error::missing("at least one seed"))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Simple,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        get_closest_location(&almanac)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let options = AlmanacOptions {
+            seed_parse_mode: SeedParseMode::Range,
+        };
+        let almanac = Almanac::parse(input, options)?;
+        get_closest_location(&almanac)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_passes() -> Result<()> {
+        let input = include_str!("example.txt");
+        let result = Day5.part1(input)?;
+        assert_eq!(result, "35");
+        Ok(())
+    }
+
+    #[test]
+    fn part2_passes() -> Result<()> {
+        let input = include_str!("example.txt");
+        let result = Day5.part2(input)?;
+        assert_eq!(result, "46");
+        Ok(())
+    }
+
+    #[test]
+    fn almanacs_without_seeds_are_an_error() {
+        let error = Day5
+            .part1("seeds:\n\nseed-to-location map:\n50 98 2")
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Expected at least one seed");
+    }
+}
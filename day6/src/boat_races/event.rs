@@ -0,0 +1,291 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use common::error::{Error, Result};
+
+use crate::boat_races::model::{Boat, BoatModel};
+use crate::boat_races::race::Race;
+use crate::boat_races::race::SolveMode;
+
+const TIME_LINE_INDEX: usize = 0;
+const RECORD_DISTANCE_LINE_INDEX: usize = 1;
+
+// retrieves a line alongside the numbers following its label
+fn get_numbers_part<'a>(
+    lines: &[&'a str],
+    line_index: usize,
+    label: &str,
+) -> Result<(&'a str, &'a str)> {
+    let line = lines
+        .get(line_index)
+        .ok_or(Error::missing(label).at_line(line_index + 1))?;
+    let (_, numbers_part) = line
+        .split_once(':')
+        .ok_or(Error::missing("':' after label").at_line(line_index + 1))?;
+    Ok((line, numbers_part))
+}
+
+fn parse_kerned_number(lines: &[&str], line_index: usize, label: &str) -> Result<u64> {
+    let (_, numbers_part) = get_numbers_part(lines, line_index, label)?;
+    let number = numbers_part
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join("");
+    number
+        .parse::<u64>()
+        .map_err(|_| Error::invalid_number(&number).at_line(line_index + 1))
+}
+
+fn parse_numbers(lines: &[&str], line_index: usize, label: &str) -> Result<Vec<u64>> {
+    let (line, numbers_part) = get_numbers_part(lines, line_index, label)?;
+    numbers_part
+        .split_whitespace()
+        .map(|number| {
+            number.parse::<u64>().map_err(|_| {
+                Error::invalid_number(number)
+                    .at_line(line_index + 1)
+                    .at_column_of(line, number)
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct SingleRaceBoatRaceEvent {
+    pub race: Race,
+}
+
+impl FromStr for SingleRaceBoatRaceEvent {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self> {
+        let lines: Vec<&str> = string.lines().collect();
+        let time = parse_kerned_number(&lines, TIME_LINE_INDEX, "time line")?;
+        let record_distance =
+            parse_kerned_number(&lines, RECORD_DISTANCE_LINE_INDEX, "record distance line")?;
+
+        let race = Race::new(time, record_distance);
+
+        Ok(Self { race })
+    }
+}
+
+#[derive(Debug)]
+pub struct BoatRaceEvent {
+    races: Vec<Race>,
+}
+
+impl BoatRaceEvent {
+    pub fn multiply_number_of_ways_each_race_record_can_be_broken(&self) -> u64 {
+        self.multiply_number_of_ways_each_race_record_can_be_broken_with(
+            &Boat::default(),
+            SolveMode::default(),
+        )
+    }
+
+    pub fn multiply_number_of_ways_each_race_record_can_be_broken_with(
+        &self,
+        model: &impl BoatModel,
+        mode: SolveMode,
+    ) -> u64 {
+        self.races.iter().fold(1, |acc, race| {
+            acc * race.count_winning_hold_times_with(model, mode)
+        })
+    }
+
+    pub fn get_winning_hold_times(
+        &self,
+        model: &impl BoatModel,
+        mode: SolveMode,
+    ) -> Vec<Option<RangeInclusive<u64>>> {
+        self.races
+            .iter()
+            .map(|race| race.get_winning_hold_times_with(model, mode))
+            .collect()
+    }
+}
+
+impl FromStr for BoatRaceEvent {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self> {
+        let lines: Vec<&str> = string.lines().collect();
+        let time_list = parse_numbers(&lines, TIME_LINE_INDEX, "time line")?;
+        let record_distance_list =
+            parse_numbers(&lines, RECORD_DISTANCE_LINE_INDEX, "record distance line")?;
+
+        if time_list.len() != record_distance_list.len() {
+            return Err(Error::invalid(&format!(
+                "Found {} race times but {} record distances",
+                time_list.len(),
+                record_distance_list.len()
+            )));
+        }
+
+        let races: Vec<Race> = time_list
+            .iter()
+            .zip(record_distance_list.iter())
+            .map(|(time, record_distance)| Race::new(*time, *record_distance))
+            .collect();
+
+        Ok(Self { races })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parsing_boat_races_from_string_succeeds() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let boat_races = BoatRaceEvent::from_str(input)?;
+        let first_race = boat_races
+            .races
+            .first()
+            .expect("Failed to retrieve first race");
+        assert_eq!(first_race.time, 7);
+        assert_eq!(first_race.record_distance, 9);
+        let second_race = boat_races
+            .races
+            .get(1)
+            .expect("Failed to retrieve second race");
+        assert_eq!(second_race.time, 15);
+        assert_eq!(second_race.record_distance, 40);
+        let third_race = boat_races
+            .races
+            .get(2)
+            .expect("Failed to retrieve third race");
+        assert_eq!(third_race.time, 30);
+        assert_eq!(third_race.record_distance, 200);
+        Ok(())
+    }
+
+    #[test]
+    fn parsing_boat_races_reports_invalid_numbers() {
+        let input = "Time:      7  15   30
+Distance:  9  4O  200";
+        let error = BoatRaceEvent::from_str(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"4O\" into a number at line 2, column 15"
+        );
+
+        let input = "Time:      7  15   30";
+        let error = SingleRaceBoatRaceEvent::from_str(input).unwrap_err();
+        assert_eq!(error.to_string(), "Expected record distance line at line 2");
+    }
+
+    #[test]
+    fn boat_race_event_evaluates_against_any_model() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let boat_races = BoatRaceEvent::from_str(input)?;
+        assert_eq!(
+            boat_races.get_winning_hold_times(&Boat::default(), SolveMode::ClosedForm),
+            vec![Some(2..=5), Some(4..=11), Some(11..=19)]
+        );
+
+        let boat = Boat {
+            acceleration: 2,
+            max_speed: Some(20),
+            max_charge_time: Some(12),
+            ..Boat::default()
+        };
+        for mode in [SolveMode::ClosedForm, SolveMode::BruteForce] {
+            assert_eq!(
+                boat_races.get_winning_hold_times(&boat, mode),
+                vec![Some(1..=6), Some(2..=12), Some(4..=12)]
+            );
+            assert_eq!(
+                boat_races.multiply_number_of_ways_each_race_record_can_be_broken_with(&boat, mode),
+                6 * 11 * 9
+            );
+        }
+        Ok(())
+    }
+
+    // travels half as far, which only `distance` knows about
+    struct HalvedBoat;
+
+    impl BoatModel for HalvedBoat {
+        fn acceleration(&self) -> u64 {
+            1
+        }
+
+        fn distance(&self, race_time: u64, charge_time: u64) -> u128 {
+            Boat::default().distance(race_time, charge_time) / 2
+        }
+    }
+
+    #[test]
+    fn models_overriding_distance_are_solved_by_brute_force() -> Result<()> {
+        let boat_races = BoatRaceEvent::from_str("Time: 7 15\nDistance: 5 20")?;
+        let winning_hold_times = vec![Some(3..=4), Some(4..=11)];
+        assert_eq!(
+            boat_races.get_winning_hold_times(&HalvedBoat, SolveMode::ClosedForm),
+            winning_hold_times
+        );
+        assert_eq!(
+            boat_races.get_winning_hold_times(&HalvedBoat, SolveMode::BruteForce),
+            winning_hold_times
+        );
+        assert_eq!(
+            boat_races.multiply_number_of_ways_each_race_record_can_be_broken_with(
+                &HalvedBoat,
+                SolveMode::ClosedForm
+            ),
+            2 * 8
+        );
+        Ok(())
+    }
+
+    // only travels after being held for an even number of ms
+    struct EvenBoat;
+
+    impl BoatModel for EvenBoat {
+        fn acceleration(&self) -> u64 {
+            1
+        }
+
+        fn distance(&self, race_time: u64, charge_time: u64) -> u128 {
+            match charge_time % 2 {
+                0 => Boat::default().distance(race_time, charge_time),
+                _ => 0,
+            }
+        }
+    }
+
+    #[test]
+    fn only_winning_hold_times_are_counted() -> Result<()> {
+        let boat_races = BoatRaceEvent::from_str("Time: 7 15\nDistance: 9 40")?;
+        assert_eq!(
+            boat_races.get_winning_hold_times(&EvenBoat, SolveMode::BruteForce),
+            vec![Some(2..=4), Some(4..=10)]
+        );
+        for mode in [SolveMode::ClosedForm, SolveMode::BruteForce] {
+            assert_eq!(
+                boat_races
+                    .multiply_number_of_ways_each_race_record_can_be_broken_with(&EvenBoat, mode),
+                2 * 4
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dragged_boats_are_solved_in_closed_form() -> Result<()> {
+        let boat_races = BoatRaceEvent::from_str("Time: 7 15\nDistance: 5 20")?;
+        let boat = Boat {
+            drag: 2,
+            ..Boat::default()
+        };
+        assert!(boat.has_closed_form());
+        for mode in [SolveMode::ClosedForm, SolveMode::BruteForce] {
+            assert_eq!(
+                boat_races.get_winning_hold_times(&boat, mode),
+                vec![Some(4..=5), Some(4..=13)]
+            );
+        }
+        Ok(())
+    }
+}
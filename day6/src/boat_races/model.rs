@@ -0,0 +1,128 @@
+// how a boat turns time spent holding the button into distance travelled
+pub trait BoatModel {
+    // speed gained, in mm/ms, for each ms the button is held
+    fn acceleration(&self) -> u64;
+
+    fn max_speed(&self) -> Option<u64> {
+        None
+    }
+
+    // speed lost, in mm/ms, to drag throughout the race
+    fn drag(&self) -> u64 {
+        0
+    }
+
+    fn min_charge_time(&self) -> Option<u64> {
+        None
+    }
+
+    fn max_charge_time(&self) -> Option<u64> {
+        None
+    }
+
+    // whether distance follows from the parameters alone, letting it be solved in closed form,
+    // otherwise every hold time is tried against `distance`
+    fn has_closed_form(&self) -> bool {
+        false
+    }
+
+    // the speed travelled at once the button is released, never below standing still
+    fn speed(&self, charge_time: u64) -> u128 {
+        let speed = self.acceleration() as u128 * charge_time as u128;
+        let speed = match self.max_speed() {
+            Some(max_speed) => speed.min(max_speed as u128),
+            None => speed,
+        };
+        speed.saturating_sub(self.drag() as u128)
+    }
+
+    // saturates rather than overflowing, as any distance beyond a u64 record breaks it anyway
+    fn distance(&self, race_time: u64, charge_time: u64) -> u128 {
+        let time_remaining = race_time.saturating_sub(charge_time);
+        self.speed(charge_time)
+            .saturating_mul(time_remaining as u128)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boat {
+    pub acceleration: u64,
+    pub max_speed: Option<u64>,
+    pub drag: u64,
+    pub min_charge_time: Option<u64>,
+    pub max_charge_time: Option<u64>,
+}
+
+// the boats from the puzzle, gaining 1 mm/ms of speed per ms held without limit
+impl Default for Boat {
+    fn default() -> Self {
+        Self {
+            acceleration: 1,
+            max_speed: None,
+            drag: 0,
+            min_charge_time: None,
+            max_charge_time: None,
+        }
+    }
+}
+
+impl BoatModel for Boat {
+    fn acceleration(&self) -> u64 {
+        self.acceleration
+    }
+
+    fn max_speed(&self) -> Option<u64> {
+        self.max_speed
+    }
+
+    fn drag(&self) -> u64 {
+        self.drag
+    }
+
+    fn min_charge_time(&self) -> Option<u64> {
+        self.min_charge_time
+    }
+
+    fn max_charge_time(&self) -> Option<u64> {
+        self.max_charge_time
+    }
+
+    fn has_closed_form(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn boat_travels_according_to_its_parameters() {
+        let boat = Boat::default();
+        assert_eq!(boat.distance(7, 0), 0);
+        assert_eq!(boat.distance(7, 3), 12);
+        assert_eq!(boat.distance(7, 7), 0);
+
+        let boat = Boat {
+            acceleration: 3,
+            max_speed: Some(10),
+            ..Boat::default()
+        };
+        assert_eq!(boat.distance(7, 2), 30);
+        assert_eq!(boat.distance(7, 4), 30);
+        assert_eq!(boat.distance(7, 6), 10);
+
+        let boat = Boat {
+            drag: 2,
+            ..Boat::default()
+        };
+        assert_eq!(boat.distance(7, 2), 0);
+        assert_eq!(boat.distance(7, 4), 6);
+
+        let boat = Boat {
+            acceleration: u64::MAX,
+            ..Boat::default()
+        };
+        assert_eq!(boat.distance(u64::MAX, u64::MAX / 2), u128::MAX);
+    }
+}
@@ -0,0 +1,330 @@
+use std::ops::RangeInclusive;
+
+use crate::boat_races::model::{Boat, BoatModel};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SolveMode {
+    #[default]
+    ClosedForm,
+    // tries every hold time, kept as a reference for the closed form
+    BruteForce,
+}
+
+#[derive(Debug)]
+pub struct Race {
+    pub time: u64,
+    pub record_distance: u64,
+}
+
+impl Race {
+    pub fn new(time: u64, record_distance: u64) -> Self {
+        Race {
+            time,
+            record_distance,
+        }
+    }
+
+    fn breaks_record(&self, model: &impl BoatModel, time_spent_holding_button_down: u64) -> bool {
+        model.distance(self.time, time_spent_holding_button_down) > self.record_distance as u128
+    }
+
+    // hold times the model allows within this race
+    fn get_charge_time_limits(&self, model: &impl BoatModel) -> Option<RangeInclusive<u64>> {
+        let shortest_hold = model.min_charge_time().unwrap_or(0);
+        let longest_hold = model.max_charge_time().unwrap_or(self.time).min(self.time);
+        (shortest_hold <= longest_hold).then_some(shortest_hold..=longest_hold)
+    }
+
+    pub fn get_number_of_ways_record_distance_can_be_broken(&self) -> u64 {
+        self.get_number_of_ways_record_distance_can_be_broken_with(SolveMode::default())
+    }
+
+    pub fn get_number_of_ways_record_distance_can_be_broken_with(&self, mode: SolveMode) -> u64 {
+        self.count_winning_hold_times_with(&Boat::default(), mode)
+    }
+
+    // counts each hold time breaking the record, as any model without a closed form may break
+    // it on hold times scattered across the race
+    pub fn count_winning_hold_times_with(&self, model: &impl BoatModel, mode: SolveMode) -> u64 {
+        match mode {
+            SolveMode::ClosedForm if model.has_closed_form() => {
+                count_hold_times(self.get_winning_hold_times_with(model, mode))
+            }
+            _ => self
+                .get_charge_time_limits(model)
+                .map_or(0, |charge_time_limits| {
+                    charge_time_limits
+                        .filter(|hold| self.breaks_record(model, *hold))
+                        .count() as u64
+                }),
+        }
+    }
+
+    pub fn get_winning_hold_times(&self, model: &impl BoatModel) -> Option<RangeInclusive<u64>> {
+        self.get_winning_hold_times_with(model, SolveMode::default())
+    }
+
+    // distance rises then falls with hold time for models with a closed form, so the hold
+    // times breaking the record are a single unbroken range. models without one are solved by
+    // brute force, spanning the shortest to the longest winning hold time
+    pub fn get_winning_hold_times_with(
+        &self,
+        model: &impl BoatModel,
+        mode: SolveMode,
+    ) -> Option<RangeInclusive<u64>> {
+        let charge_time_limits = self.get_charge_time_limits(model)?;
+        let winning_hold_times = match mode {
+            SolveMode::ClosedForm if model.has_closed_form() => self.solve_by_closed_form(model)?,
+            _ => return self.solve_by_brute_force(model, charge_time_limits),
+        };
+
+        let shortest_hold = *winning_hold_times.start().max(charge_time_limits.start());
+        let longest_hold = *winning_hold_times.end().min(charge_time_limits.end());
+        (shortest_hold <= longest_hold).then_some(shortest_hold..=longest_hold)
+    }
+
+    fn solve_by_brute_force(
+        &self,
+        model: &impl BoatModel,
+        charge_time_limits: RangeInclusive<u64>,
+    ) -> Option<RangeInclusive<u64>> {
+        let mut winning_hold_times: Option<RangeInclusive<u64>> = None;
+        for time_spent_holding_button_down in charge_time_limits {
+            if self.breaks_record(model, time_spent_holding_button_down) {
+                let shortest_hold = winning_hold_times
+                    .map_or(time_spent_holding_button_down, |hold_times| {
+                        *hold_times.start()
+                    });
+                winning_hold_times = Some(shortest_hold..=time_spent_holding_button_down);
+            }
+        }
+
+        winning_hold_times
+    }
+
+    fn solve_by_closed_form(&self, model: &impl BoatModel) -> Option<RangeInclusive<u64>> {
+        let acceleration = model.acceleration();
+        if acceleration == 0 {
+            return None;
+        }
+        if model.drag() > 0 {
+            return self.solve_by_bisection(model);
+        }
+
+        // a * h * (t - h) > d holds exactly when h * (t - h) > d / a, rounding down
+        let accelerating_hold_times =
+            get_quadratic_winning_hold_times(self.time, self.record_distance / acceleration);
+
+        let max_speed = match model.max_speed() {
+            Some(max_speed) => max_speed,
+            None => return accelerating_hold_times,
+        };
+
+        // from this hold time on the boat is at its maximum speed
+        let capped_hold = max_speed.div_ceil(acceleration);
+
+        let accelerating_hold_times = accelerating_hold_times.and_then(|hold_times| {
+            let longest_hold = (*hold_times.end()).min(capped_hold.checked_sub(1)?);
+            (*hold_times.start() <= longest_hold).then_some(*hold_times.start()..=longest_hold)
+        });
+
+        // s * (t - h) > d holds exactly when t - h > d / s, rounding down
+        let capped_hold_times = (max_speed > 0)
+            .then(|| self.record_distance / max_speed)
+            .and_then(|time_remaining| self.time.checked_sub(time_remaining)?.checked_sub(1))
+            .and_then(|longest_hold| {
+                (capped_hold <= longest_hold).then_some(capped_hold..=longest_hold)
+            });
+
+        match (accelerating_hold_times, capped_hold_times) {
+            (Some(accelerating), Some(capped)) => Some(*accelerating.start()..=*capped.end()),
+            (accelerating, capped) => accelerating.or(capped),
+        }
+    }
+
+    // drag only lowers the speed travelled at, so distance still rises to a peak & falls from
+    // there, letting each side of the furthest travelling hold time be bisected
+    fn solve_by_bisection(&self, model: &impl BoatModel) -> Option<RangeInclusive<u64>> {
+        let acceleration = model.acceleration() as u128;
+        // where (a * h - drag) * (t - h) peaks, or where the boat reaches its maximum speed
+        let peak = (acceleration * self.time as u128 + model.drag() as u128) / (2 * acceleration);
+        let capped_hold = model
+            .max_speed()
+            .map(|max_speed| (max_speed as u128).div_ceil(acceleration));
+        let furthest_hold = [
+            Some(peak),
+            Some(peak + 1),
+            capped_hold,
+            capped_hold.map(|hold| hold.saturating_sub(1)),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|hold| hold.min(self.time as u128) as u64)
+        .max_by_key(|hold| model.distance(self.time, *hold))?;
+        if !self.breaks_record(model, furthest_hold) {
+            return None;
+        }
+
+        let (mut low, mut high) = (0, furthest_hold);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.breaks_record(model, middle) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        let shortest_hold = low;
+
+        let (mut low, mut high) = (furthest_hold, self.time);
+        while low < high {
+            let middle = high - (high - low) / 2;
+            if self.breaks_record(model, middle) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        Some(shortest_hold..=low)
+    }
+}
+
+pub fn count_hold_times(hold_times: Option<RangeInclusive<u64>>) -> u64 {
+    hold_times.map_or(0, |hold_times| hold_times.end() - hold_times.start() + 1)
+}
+
+// hold times h satisfying h * (t - h) > d lie strictly between the roots
+// (t ± sqrt(t² - 4d)) / 2, symmetric around t / 2
+fn get_quadratic_winning_hold_times(
+    time: u64,
+    record_distance: u64,
+) -> Option<RangeInclusive<u64>> {
+    let breaks_record = |hold: u64| hold as u128 * (time - hold) as u128 > record_distance as u128;
+
+    let discriminant = (time as u128 * time as u128).checked_sub(4 * record_distance as u128)?;
+
+    // the integer square root lands the estimate within a step of the lowest winning hold
+    let mut shortest_hold = ((time as u128 - discriminant.isqrt()) / 2) as u64;
+    while shortest_hold > 0 && breaks_record(shortest_hold - 1) {
+        shortest_hold -= 1;
+    }
+    while shortest_hold <= time / 2 && !breaks_record(shortest_hold) {
+        shortest_hold += 1;
+    }
+
+    (shortest_hold <= time / 2).then(|| shortest_hold..=time - shortest_hold)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::boat_races::event::BoatRaceEvent;
+    use common::error::Result;
+    use std::str::FromStr;
+
+    #[test]
+    fn get_number_of_ways_record_distance_can_be_broken() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let boat_races = BoatRaceEvent::from_str(input)?;
+        let result = boat_races.multiply_number_of_ways_each_race_record_can_be_broken();
+        assert_eq!(result, 288);
+        Ok(())
+    }
+
+    #[test]
+    fn closed_form_matches_brute_force() {
+        for time in 0..80 {
+            // every record up to & just past the furthest possible distance, which includes
+            // each record exactly matched by some hold time
+            for record_distance in 0..=(time * time / 4 + 2) {
+                let race = Race::new(time, record_distance);
+                assert_eq!(
+                    race.get_number_of_ways_record_distance_can_be_broken_with(
+                        SolveMode::ClosedForm
+                    ),
+                    race.get_number_of_ways_record_distance_can_be_broken_with(
+                        SolveMode::BruteForce
+                    ),
+                    "time {time}, record distance {record_distance}"
+                );
+            }
+        }
+
+        // longer races, with records either side of the distance a random hold time travels
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..500 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let time = seed % 10_000;
+            let hold = (seed >> 32) % (time + 1);
+            let distance = hold * (time - hold);
+            for record_distance in distance.saturating_sub(1)..=distance + 1 {
+                let race = Race::new(time, record_distance);
+                assert_eq!(
+                    race.get_number_of_ways_record_distance_can_be_broken_with(
+                        SolveMode::ClosedForm
+                    ),
+                    race.get_number_of_ways_record_distance_can_be_broken_with(
+                        SolveMode::BruteForce
+                    ),
+                    "time {time}, record distance {record_distance}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn closed_form_handles_large_races() {
+        let race = Race::new(71530, 940200);
+        assert_eq!(
+            race.get_number_of_ways_record_distance_can_be_broken(),
+            71503
+        );
+
+        // record matched exactly by holding for half of the time
+        let race = Race::new(1 << 32, 1 << 62);
+        assert_eq!(race.get_number_of_ways_record_distance_can_be_broken(), 0);
+        let race = Race::new(1 << 32, (1 << 62) - 1);
+        assert_eq!(race.get_number_of_ways_record_distance_can_be_broken(), 1);
+
+        let race = Race::new(u64::MAX, u64::MAX);
+        assert_eq!(
+            race.get_number_of_ways_record_distance_can_be_broken(),
+            u64::MAX - 3
+        );
+    }
+
+    #[test]
+    fn closed_form_matches_brute_force_for_any_boat() {
+        let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        for _ in 0..2000 {
+            let boat = Boat {
+                acceleration: next() % 5,
+                max_speed: (next() % 3 > 0).then(|| next() % 60),
+                drag: if next() % 3 == 0 { next() % 20 } else { 0 },
+                min_charge_time: (next() % 3 == 0).then(|| next() % 40),
+                max_charge_time: (next() % 3 == 0).then(|| next() % 80),
+            };
+            let time = next() % 80;
+            let hold = next() % (time + 1);
+            let distance = boat.distance(time, hold) as u64;
+            for record_distance in distance.saturating_sub(1)..=distance + 1 {
+                let race = Race::new(time, record_distance);
+                assert_eq!(
+                    race.get_winning_hold_times_with(&boat, SolveMode::ClosedForm),
+                    race.get_winning_hold_times_with(&boat, SolveMode::BruteForce),
+                    "{boat:?}, time {time}, record distance {record_distance}"
+                );
+            }
+        }
+    }
+}
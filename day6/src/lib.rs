@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use common::error::Result;
+use common::Solution;
+
+pub mod boat_races;
+
+use boat_races::event::{BoatRaceEvent, SingleRaceBoatRaceEvent};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        let boat_races = BoatRaceEvent::from_str(input)?;
+        let result = boat_races.multiply_number_of_ways_each_race_record_can_be_broken();
+        Ok(result.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let event = SingleRaceBoatRaceEvent::from_str(input)?;
+        let result = event
+            .race
+            .get_number_of_ways_record_distance_can_be_broken();
+        Ok(result.to_string())
+    }
+}
@@ -1,5 +0,0 @@
-#[derive(Debug)]
-pub enum Error {
-    HandNotParsableError,
-    LabelCountError(String),
-}
@@ -0,0 +1,447 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::iter::zip;
+
+use crate::camel_cards::game::Game;
+use crate::camel_cards::hand::{Hand, HandType};
+use crate::camel_cards::ruleset::Ruleset;
+
+// a wildcard, by its position in the hand, alongside the label it stood in for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Substitution {
+    pub position: usize,
+    pub wild_label: char,
+    pub label: char,
+}
+
+// what decided the order of a hand & one of its neighbours
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decider {
+    HandType {
+        hand_type: HandType,
+        neighbour_hand_type: HandType,
+    },
+    // the first card, by its 1-based position, which differs between the hands
+    Card {
+        position: usize,
+        label: char,
+        neighbour_label: char,
+    },
+    Identical,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub neighbour: String,
+    pub decider: Decider,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandExplanation {
+    // 1 being the weakest hand
+    pub rank: usize,
+    pub labels: String,
+    pub bid: u64,
+    pub hand_type: HandType,
+    pub substitutions: Vec<Substitution>,
+    pub against_weaker: Option<Comparison>,
+    pub against_stronger: Option<Comparison>,
+    pub winnings: u64,
+}
+
+fn get_labels(hand: &Hand) -> String {
+    hand.cards.iter().map(|card| card.label).collect()
+}
+
+// wildcards join the most common label, the strongest of them when several are as common, or
+// the strongest label the ruleset doesn't make wild when every card is wild
+fn get_substitutions(hand: &Hand, ruleset: &Ruleset) -> Vec<Substitution> {
+    let mut label_counts: BTreeMap<char, (usize, usize)> = BTreeMap::new();
+    for card in hand.cards.iter().filter(|card| !card.wild) {
+        label_counts
+            .entry(card.label)
+            .or_insert((0, card.strength))
+            .0 += 1;
+    }
+
+    let substituted_label = match label_counts
+        .iter()
+        .max_by_key(|(_, (count, strength))| (*count, *strength))
+    {
+        Some((label, _)) => *label,
+        None => match ruleset
+            .ranks()
+            .iter()
+            .find(|label| !ruleset.is_wild(**label))
+            .or(ruleset.ranks().first())
+        {
+            Some(label) => *label,
+            None => return vec![],
+        },
+    };
+
+    hand.cards
+        .iter()
+        .enumerate()
+        .filter(|(_, card)| card.wild)
+        .map(|(index, card)| Substitution {
+            position: index + 1,
+            wild_label: card.label,
+            label: substituted_label,
+        })
+        .collect()
+}
+
+fn compare(hand: &Hand, neighbour: &Hand) -> Comparison {
+    let decider = match hand.hand_type.cmp(&neighbour.hand_type) {
+        Ordering::Equal => zip(&hand.cards, &neighbour.cards)
+            .enumerate()
+            .find(|(_, (card, neighbour_card))| card != neighbour_card)
+            .map_or(Decider::Identical, |(index, (card, neighbour_card))| {
+                Decider::Card {
+                    position: index + 1,
+                    label: card.label,
+                    neighbour_label: neighbour_card.label,
+                }
+            }),
+        _ => Decider::HandType {
+            hand_type: hand.hand_type.clone(),
+            neighbour_hand_type: neighbour.hand_type.clone(),
+        },
+    };
+
+    Comparison {
+        neighbour: get_labels(neighbour),
+        decider,
+    }
+}
+
+// explains each hand's rank, strongest hand first, matching the game's order
+pub fn explain(game: &Game, ruleset: &Ruleset) -> Vec<HandExplanation> {
+    let hand_count = game.hands.len();
+    game.hands
+        .iter()
+        .enumerate()
+        .map(|(index, hand)| {
+            let rank = hand_count - index;
+            HandExplanation {
+                rank,
+                labels: get_labels(hand),
+                bid: hand.bid,
+                hand_type: hand.hand_type.clone(),
+                substitutions: get_substitutions(hand, ruleset),
+                against_weaker: game
+                    .hands
+                    .get(index + 1)
+                    .map(|weaker| compare(hand, weaker)),
+                against_stronger: index
+                    .checked_sub(1)
+                    .map(|stronger_index| compare(hand, &game.hands[stronger_index])),
+                winnings: rank as u64 * hand.bid,
+            }
+        })
+        .collect()
+}
+
+fn describe_substitutions(substitutions: &[Substitution]) -> String {
+    if substitutions.is_empty() {
+        return String::from("-");
+    }
+    substitutions
+        .iter()
+        .map(|substitution| {
+            format!(
+                "{}@{}->{}",
+                substitution.wild_label, substitution.position, substitution.label
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn describe_comparison(comparison: &Option<Comparison>) -> String {
+    let comparison = match comparison {
+        Some(comparison) => comparison,
+        None => return String::from("-"),
+    };
+
+    let reason = match &comparison.decider {
+        Decider::HandType {
+            hand_type,
+            neighbour_hand_type,
+        } => format!("{} vs {}", hand_type, neighbour_hand_type),
+        Decider::Card {
+            position,
+            label,
+            neighbour_label,
+        } => format!("card {}: {} vs {}", position, label, neighbour_label),
+        Decider::Identical => String::from("identical"),
+    };
+
+    format!("{} ({})", comparison.neighbour, reason)
+}
+
+pub fn to_table(explanations: &[HandExplanation]) -> String {
+    let header = [
+        "Rank",
+        "Hand",
+        "Type",
+        "Bid",
+        "Winnings",
+        "Wildcards",
+        "Against weaker",
+        "Against stronger",
+    ]
+    .map(String::from);
+
+    let rows: Vec<[String; 8]> = explanations
+        .iter()
+        .map(|explanation| {
+            [
+                explanation.rank.to_string(),
+                explanation.labels.clone(),
+                explanation.hand_type.to_string(),
+                explanation.bid.to_string(),
+                explanation.winnings.to_string(),
+                describe_substitutions(&explanation.substitutions),
+                describe_comparison(&explanation.against_weaker),
+                describe_comparison(&explanation.against_stronger),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(rows.iter())
+        .map(|row| {
+            row.iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            character if character.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn comparison_to_json(comparison: &Option<Comparison>) -> String {
+    let comparison = match comparison {
+        Some(comparison) => comparison,
+        None => return String::from("null"),
+    };
+
+    let decider = match &comparison.decider {
+        Decider::HandType {
+            hand_type,
+            neighbour_hand_type,
+        } => format!(
+            "\"decider\":\"hand_type\",\"hand_type\":{},\"neighbour_hand_type\":{}",
+            json_string(&hand_type.to_string()),
+            json_string(&neighbour_hand_type.to_string())
+        ),
+        Decider::Card {
+            position,
+            label,
+            neighbour_label,
+        } => format!(
+            "\"decider\":\"card\",\"position\":{},\"label\":{},\"neighbour_label\":{}",
+            position,
+            json_string(&label.to_string()),
+            json_string(&neighbour_label.to_string())
+        ),
+        Decider::Identical => String::from("\"decider\":\"identical\""),
+    };
+
+    format!(
+        "{{\"neighbour\":{},{}}}",
+        json_string(&comparison.neighbour),
+        decider
+    )
+}
+
+pub fn to_json(explanations: &[HandExplanation]) -> String {
+    let hands: Vec<String> = explanations
+        .iter()
+        .map(|explanation| {
+            let substitutions: Vec<String> = explanation
+                .substitutions
+                .iter()
+                .map(|substitution| {
+                    format!(
+                        "{{\"position\":{},\"wild_label\":{},\"label\":{}}}",
+                        substitution.position,
+                        json_string(&substitution.wild_label.to_string()),
+                        json_string(&substitution.label.to_string())
+                    )
+                })
+                .collect();
+
+            format!(
+                "{{\"rank\":{},\"hand\":{},\"hand_type\":{},\"bid\":{},\"winnings\":{},\"substitutions\":[{}],\"against_weaker\":{},\"against_stronger\":{}}}",
+                explanation.rank,
+                json_string(&explanation.labels),
+                json_string(&explanation.hand_type.to_string()),
+                explanation.bid,
+                explanation.winnings,
+                substitutions.join(","),
+                comparison_to_json(&explanation.against_weaker),
+                comparison_to_json(&explanation.against_stronger)
+            )
+        })
+        .collect();
+
+    format!("[{}]", hands.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::camel_cards::ruleset::Ruleset;
+    use common::error::Result;
+
+    #[test]
+    fn explanations_describe_each_hands_rank() -> Result<()> {
+        let input = include_str!("../example.txt");
+        let ruleset = Ruleset::jokers_wild();
+        let game = Game::parse_with(input, &ruleset)?;
+        let explanations = explain(&game, &ruleset);
+
+        let ranks: Vec<(&str, usize)> = explanations
+            .iter()
+            .map(|explanation| (explanation.labels.as_str(), explanation.rank))
+            .collect();
+        assert_eq!(
+            ranks,
+            vec![
+                ("KTJJT", 5),
+                ("QQQJA", 4),
+                ("T55J5", 3),
+                ("KK677", 2),
+                ("32T3K", 1)
+            ]
+        );
+        assert_eq!(
+            explanations
+                .iter()
+                .map(|explanation| explanation.winnings)
+                .sum::<u64>(),
+            5905
+        );
+
+        let strongest = &explanations[0];
+        assert_eq!(strongest.hand_type, HandType::FourOfAKind);
+        assert_eq!(
+            strongest.substitutions,
+            vec![
+                Substitution {
+                    position: 3,
+                    wild_label: 'J',
+                    label: 'T',
+                },
+                Substitution {
+                    position: 4,
+                    wild_label: 'J',
+                    label: 'T',
+                },
+            ]
+        );
+        assert_eq!(strongest.against_stronger, None);
+        assert_eq!(
+            strongest.against_weaker,
+            Some(Comparison {
+                neighbour: String::from("QQQJA"),
+                decider: Decider::Card {
+                    position: 1,
+                    label: 'K',
+                    neighbour_label: 'Q',
+                },
+            })
+        );
+
+        let weakest = &explanations[4];
+        assert!(weakest.substitutions.is_empty());
+        assert_eq!(
+            weakest.against_stronger,
+            Some(Comparison {
+                neighbour: String::from("KK677"),
+                decider: Decider::HandType {
+                    hand_type: HandType::OnePair,
+                    neighbour_hand_type: HandType::TwoPair,
+                },
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn all_wild_hands_substitute_the_strongest_label() -> Result<()> {
+        let ruleset = Ruleset::jokers_wild();
+        let game = Game::parse_with("JJJJJ 10", &ruleset)?;
+        let explanations = explain(&game, &ruleset);
+
+        assert_eq!(explanations[0].hand_type, HandType::FiveOfAKind);
+        let substitutions: Vec<(usize, char, char)> = explanations[0]
+            .substitutions
+            .iter()
+            .map(|substitution| {
+                (
+                    substitution.position,
+                    substitution.wild_label,
+                    substitution.label,
+                )
+            })
+            .collect();
+        assert_eq!(
+            substitutions,
+            (1..=5)
+                .map(|position| (position, 'J', 'A'))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn explanations_render_as_table_and_json() -> Result<()> {
+        let ruleset = Ruleset::jokers_wild();
+        let game = Game::parse_with("QQQJA 483\nKTJJT 220", &ruleset)?;
+        let explanations = explain(&game, &ruleset);
+
+        assert_eq!(
+            to_table(&explanations),
+            "Rank  Hand   Type            Bid  Winnings  Wildcards      Against weaker          Against stronger
+2     KTJJT  Four of a kind  220  440       J@3->T J@4->T  QQQJA (card 1: K vs Q)  -
+1     QQQJA  Four of a kind  483  483       J@4->Q         -                       KTJJT (card 1: Q vs K)"
+        );
+
+        assert_eq!(
+            to_json(&explanations[1..]),
+            "[{\"rank\":1,\"hand\":\"QQQJA\",\"hand_type\":\"Four of a kind\",\"bid\":483,\"winnings\":483,\"substitutions\":[{\"position\":4,\"wild_label\":\"J\",\"label\":\"Q\"}],\"against_weaker\":null,\"against_stronger\":{\"neighbour\":\"KTJJT\",\"decider\":\"card\",\"position\":1,\"label\":\"Q\",\"neighbour_label\":\"K\"}}]"
+        );
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\u000a\"");
+        Ok(())
+    }
+}
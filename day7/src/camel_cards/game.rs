@@ -1,7 +1,9 @@
 use std::str::FromStr;
 
-use crate::camel_cards::error::Error;
+use common::error::{Error, Result};
+
 use crate::camel_cards::hand::Hand;
+use crate::camel_cards::ruleset::Ruleset;
 
 #[derive(Debug)]
 pub struct Game {
@@ -10,21 +12,30 @@ pub struct Game {
 
 impl FromStr for Game {
     type Err = Error;
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let mut hands: Vec<Hand> = string
+    fn from_str(string: &str) -> Result<Self> {
+        Self::parse_with(string, &Ruleset::standard())
+    }
+}
+
+impl Game {
+    // ranks the hands, strongest first
+    pub fn new(mut hands: Vec<Hand>) -> Self {
+        hands.sort_by(|a, b| b.cmp(a));
+        Self { hands }
+    }
+
+    pub fn parse_with(string: &str, ruleset: &Ruleset) -> Result<Self> {
+        let hands: Vec<Hand> = string
             .lines()
-            .map(|line| {
-                Hand::from_str(line).expect(format!("Could not parse line {}", line).as_str())
+            .enumerate()
+            .map(|(line_index, line)| {
+                Hand::parse_with(line, ruleset).map_err(|error| error.at_line(line_index + 1))
             })
-            .collect();
-
-        hands.sort_by(|a, b| b.partial_cmp(&a).unwrap());
+            .collect::<Result<_>>()?;
 
-        Ok(Self { hands })
+        Ok(Self::new(hands))
     }
-}
 
-impl Game {
     pub fn calculate_winnings(&self) -> u64 {
         self.hands.iter().enumerate().fold(0, |acc, (index, hand)| {
             let multiplier = self.hands.len() - index;
@@ -39,7 +50,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn game_can_be_parsed() -> Result<(), Error> {
+    fn game_can_be_parsed() -> Result<()> {
         let input = include_str!("../example.txt");
         let game = Game::from_str(input)?;
         assert!(!game.hands.is_empty());
@@ -47,7 +58,17 @@ mod test {
     }
 
     #[test]
-    fn game_calculates_winnings_correctly() -> Result<(), Error> {
+    fn game_parse_reports_line_of_invalid_hand() {
+        let input = "32T3K 765\nT55J5 684\nKK6Z7 28";
+        let error = Game::from_str(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unexpected character 'Z' at line 3, column 4"
+        );
+    }
+
+    #[test]
+    fn game_calculates_winnings_correctly() -> Result<()> {
         let input = include_str!("../example.txt");
         let game = Game::from_str(input)?;
         let winnings = game.calculate_winnings();
@@ -56,10 +77,10 @@ mod test {
     }
 
     #[test]
-    fn orders_hands_correctly() -> Result<(), Error> {
+    fn orders_hands_correctly() -> Result<()> {
         let input = include_str!("../example.txt");
         let game = Game::from_str(input)?;
-        let first_hand = game.hands.get(0).unwrap();
+        let first_hand = game.hands.first().unwrap();
         assert_eq!(first_hand.bid, 483);
         let second_hand = game.hands.get(1).unwrap();
         assert_eq!(second_hand.bid, 684);
@@ -73,12 +94,35 @@ mod test {
     }
 
     #[test]
-    fn game_calculates_winnings_correctly_in_joker_mode() -> Result<(), Error> {
-        std::env::set_var("JOKER_MODE", "true");
+    fn game_calculates_winnings_correctly_in_joker_mode() -> Result<()> {
         let input = include_str!("../example.txt");
-        let game = Game::from_str(input)?;
+        let game = Game::parse_with(input, &Ruleset::jokers_wild())?;
         let winnings = game.calculate_winnings();
         assert_eq!(winnings, 5905);
+        // both rule sets can be scored side by side
+        let game = Game::parse_with(input, &Ruleset::standard())?;
+        assert_eq!(game.calculate_winnings(), 6440);
+        Ok(())
+    }
+
+    #[test]
+    fn game_calculates_winnings_under_house_rules() -> Result<()> {
+        let rules_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("rules");
+        let input = include_str!("../example.txt");
+
+        let ruleset = Ruleset::load(&rules_dir.join("jokers-wild.txt"))?;
+        let game = Game::parse_with(input, &ruleset)?;
+        assert_eq!(game.calculate_winnings(), 5905);
+
+        // 32T3K becomes three of a kind & QQQJA stays the strongest hand
+        let ruleset = Ruleset::load(&rules_dir.join("deuces-wild.txt"))?;
+        let game = Game::parse_with(input, &ruleset)?;
+        let bids: Vec<u64> = game.hands.iter().map(|hand| hand.bid).collect();
+        assert_eq!(bids, vec![483, 684, 765, 28, 220]);
+        assert_eq!(
+            game.calculate_winnings(),
+            483 * 5 + 684 * 4 + 765 * 3 + 28 * 2 + 220
+        );
         Ok(())
     }
 }
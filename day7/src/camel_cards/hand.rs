@@ -1,13 +1,25 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::fmt;
 use std::iter::zip;
-use std::ops::Add;
 use std::str::FromStr;
 
-use crate::camel_cards::card::{Card, CardType};
-use crate::camel_cards::error::Error;
-
-#[derive(Debug, PartialEq, Eq, Ord)]
+use crate::camel_cards::card::Card;
+use crate::camel_cards::ruleset::Ruleset;
+use common::error::{Error, Result};
+
+// signatures of the named hand types, from strongest to weakest
+const FIVE_OF_A_KIND: &[usize] = &[5];
+const FOUR_OF_A_KIND: &[usize] = &[4, 1];
+const FULL_HOUSE: &[usize] = &[3, 2];
+const THREE_OF_A_KIND: &[usize] = &[3, 1, 1];
+const TWO_PAIR: &[usize] = &[2, 2, 1];
+const ONE_PAIR: &[usize] = &[2, 1, 1, 1];
+const HIGH_CARD: &[usize] = &[1, 1, 1, 1, 1];
+
+// a hand's type is the number of cards sharing each label, largest first, so hands of any
+// size can be classified. The named types are the signatures of five card hands
+#[derive(Debug, Clone)]
 pub enum HandType {
     FiveOfAKind,
     FourOfAKind,
@@ -16,118 +28,115 @@ pub enum HandType {
     TwoPair,
     OnePair,
     HighCard,
+    Other(Vec<usize>),
 }
 
-impl PartialOrd for HandType {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        use HandType::*;
-
-        Some(match (self, other) {
-            (FiveOfAKind, FiveOfAKind) => Ordering::Equal,
-            (FiveOfAKind, _) => Ordering::Greater,
-            (_, FiveOfAKind) => Ordering::Less,
-
-            (FourOfAKind, FourOfAKind) => Ordering::Equal,
-            (FourOfAKind, _) => Ordering::Greater,
-            (_, FourOfAKind) => Ordering::Less,
+impl HandType {
+    pub fn from_signature(signature: Vec<usize>) -> Self {
+        match signature.as_slice() {
+            FIVE_OF_A_KIND => HandType::FiveOfAKind,
+            FOUR_OF_A_KIND => HandType::FourOfAKind,
+            FULL_HOUSE => HandType::FullHouse,
+            THREE_OF_A_KIND => HandType::ThreeOfAKind,
+            TWO_PAIR => HandType::TwoPair,
+            ONE_PAIR => HandType::OnePair,
+            HIGH_CARD => HandType::HighCard,
+            _ => HandType::Other(signature),
+        }
+    }
 
-            (FullHouse, FullHouse) => Ordering::Equal,
-            (FullHouse, _) => Ordering::Greater,
-            (_, FullHouse) => Ordering::Less,
+    pub fn signature(&self) -> &[usize] {
+        match self {
+            HandType::FiveOfAKind => FIVE_OF_A_KIND,
+            HandType::FourOfAKind => FOUR_OF_A_KIND,
+            HandType::FullHouse => FULL_HOUSE,
+            HandType::ThreeOfAKind => THREE_OF_A_KIND,
+            HandType::TwoPair => TWO_PAIR,
+            HandType::OnePair => ONE_PAIR,
+            HandType::HighCard => HIGH_CARD,
+            HandType::Other(signature) => signature,
+        }
+    }
+}
 
-            (ThreeOfAKind, ThreeOfAKind) => Ordering::Equal,
-            (ThreeOfAKind, _) => Ordering::Greater,
-            (_, ThreeOfAKind) => Ordering::Less,
+impl PartialEq for HandType {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
 
-            (TwoPair, TwoPair) => Ordering::Equal,
-            (TwoPair, _) => Ordering::Greater,
-            (_, TwoPair) => Ordering::Less,
+impl Eq for HandType {}
 
-            (OnePair, OnePair) => Ordering::Equal,
-            (OnePair, _) => Ordering::Greater,
-            (_, OnePair) => Ordering::Less,
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-            (HighCard, HighCard) => Ordering::Equal,
-        })
+// the larger the biggest group of labels the stronger the hand, falling back on the next
+// biggest group when they match
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.signature().cmp(other.signature())
     }
 }
 
 impl TryFrom<&Vec<Card>> for HandType {
     type Error = Error;
 
-    fn try_from(cards: &Vec<Card>) -> Result<Self, Self::Error> {
-        let joker_mode = match std::env::var("JOKER_MODE") {
-            Ok(value) => value == "true",
-            Err(_) => false,
-        };
+    fn try_from(cards: &Vec<Card>) -> Result<Self> {
+        if cards.is_empty() {
+            return Err(Error::missing("at least one card"));
+        }
+
+        Ok(HandType::classify(cards, true))
+    }
+}
 
-        let mut label_counts: HashMap<char, u64> = HashMap::new();
-        let mut label_counts_contain_jokers = false;
+impl HandType {
+    fn classify(cards: &[Card], use_wildcards: bool) -> Self {
+        let mut label_counts: BTreeMap<char, usize> = BTreeMap::new();
+        let mut wild_count = 0;
         for card in cards.iter() {
-            let label = card.label;
-            if label == 'J' && joker_mode == true {
-                label_counts_contain_jokers = true;
+            if card.wild && use_wildcards {
+                wild_count += 1;
+            } else {
+                *label_counts.entry(card.label).or_default() += 1;
             }
-
-            match label_counts.get(&label) {
-                Some(count) => label_counts.insert(label, count.add(1)),
-                None => label_counts.insert(label, 1),
-            };
         }
 
-        // wrong
-        // we don't make joker increase highest card
-        // we add jokers to highest count in hand,
-        // enabling hand to retrieve best possible hand type
-
-        if joker_mode && label_counts_contain_jokers && label_counts.len() > 1 {
-            if let Some(joker_count) = label_counts.remove(&'J') {
-                if let Some((label_with_highest_count, _)) = label_counts
-                    .iter()
-                    .max_by(|(_, x_count), (_, y_count)| x_count.cmp(y_count))
-                {
-                    if let Some(highest_label_count) = label_counts.get(&label_with_highest_count) {
-                        label_counts.insert(
-                            *label_with_highest_count,
-                            highest_label_count.add(joker_count),
-                        );
-                    }
-                }
-            }
+        let mut signature: Vec<usize> = label_counts.into_values().collect();
+        signature.sort_by(|a, b| b.cmp(a));
+
+        // wildcards join the most common label, giving the best possible hand type
+        match signature.first_mut() {
+            Some(highest_count) => *highest_count += wild_count,
+            None => signature.push(wild_count),
         }
 
-        match label_counts.len() {
-            1 => Ok(HandType::FiveOfAKind),
-            2 => match label_counts.values().nth(0) {
-                Some(count) if *count == 1 || *count == 4 => Ok(HandType::FourOfAKind),
-                Some(count) if *count == 2 || *count == 3 => Ok(HandType::FullHouse),
-                Some(count) => Err(Error::LabelCountError(format!(
-                    "Unhandled count {} in case of 2 labels",
-                    count
-                ))),
-                None => Err(Error::LabelCountError(
-                    "Count resulted to none in case of 2 labels".to_string(),
-                )),
-            },
-            3 => {
-                for count in label_counts.values() {
-                    if *count == 3 {
-                        return Ok(HandType::ThreeOfAKind);
-                    }
-                }
-                return Ok(HandType::TwoPair);
+        HandType::from_signature(signature)
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandType::FiveOfAKind => write!(f, "Five of a kind"),
+            HandType::FourOfAKind => write!(f, "Four of a kind"),
+            HandType::FullHouse => write!(f, "Full house"),
+            HandType::ThreeOfAKind => write!(f, "Three of a kind"),
+            HandType::TwoPair => write!(f, "Two pair"),
+            HandType::OnePair => write!(f, "One pair"),
+            HandType::HighCard => write!(f, "High card"),
+            HandType::Other(signature) => {
+                let counts: Vec<String> = signature.iter().map(|count| count.to_string()).collect();
+                write!(f, "Groups of {}", counts.join("-"))
             }
-            4 => Ok(HandType::OnePair),
-            5 => Ok(HandType::HighCard),
-            _ => Err(Error::LabelCountError(format!(
-                "Couldn't catch label count of {}",
-                label_counts.len(),
-            ))),
         }
     }
 }
 
-#[derive(Debug, Ord)]
+#[derive(Debug)]
 pub struct Hand {
     pub cards: Vec<Card>,
     pub bid: u64,
@@ -136,20 +145,7 @@ pub struct Hand {
 
 impl PartialEq for Hand {
     fn eq(&self, other: &Self) -> bool {
-        match self.hand_type.partial_cmp(&other.hand_type).unwrap() {
-            Ordering::Greater => false,
-            Ordering::Less => false,
-            Ordering::Equal => {
-                for (card, other_card) in zip(&self.cards, &other.cards) {
-                    match card.partial_cmp(other_card).unwrap() {
-                        Ordering::Greater => return false,
-                        Ordering::Less => return false,
-                        Ordering::Equal => continue,
-                    }
-                }
-                true
-            }
-        }
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -157,18 +153,24 @@ impl Eq for Hand {}
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.hand_type.partial_cmp(&other.hand_type).unwrap() {
-            Ordering::Greater => Some(Ordering::Greater),
-            Ordering::Less => Some(Ordering::Less),
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Less => Ordering::Less,
             Ordering::Equal => {
                 for (card, other_card) in zip(&self.cards, &other.cards) {
-                    match card.partial_cmp(other_card).unwrap() {
-                        Ordering::Greater => return Some(Ordering::Greater),
-                        Ordering::Less => return Some(Ordering::Less),
+                    match card.cmp(other_card) {
+                        Ordering::Greater => return Ordering::Greater,
+                        Ordering::Less => return Ordering::Less,
                         Ordering::Equal => continue,
                     }
                 }
-                Some(Ordering::Equal)
+                Ordering::Equal
             }
         }
     }
@@ -176,28 +178,51 @@ impl PartialOrd for Hand {
 
 impl FromStr for Hand {
     type Err = Error;
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let (card_labels, bid) = string.split_at(5);
+    fn from_str(string: &str) -> Result<Self> {
+        Self::parse_with(string, &Ruleset::standard())
+    }
+}
 
-        let joker_mode = match std::env::var("JOKER_MODE") {
-            Ok(value) => value == "true",
-            Err(_) => false,
-        };
+impl Hand {
+    pub fn new(cards: Vec<Card>, bid: u64) -> Result<Self> {
+        let hand_type = HandType::try_from(&cards)?;
+        Ok(Self {
+            cards,
+            bid,
+            hand_type,
+        })
+    }
 
-        let cards: Vec<Card> = card_labels
-            .chars()
-            .map(|label| Card::new(label, joker_mode))
-            .collect();
+    // the type the hand would have if its wildcards only counted as their own label
+    pub fn get_hand_type_without_wildcards(&self) -> HandType {
+        HandType::classify(&self.cards, false)
+    }
 
-        let hand_type = HandType::try_from(&cards)?;
+    pub fn parse_with(string: &str, ruleset: &Ruleset) -> Result<Self> {
+        let (card_labels, bid) = string
+            .trim_end()
+            .split_once(' ')
+            .ok_or(Error::missing("' ' between cards and bid"))?;
+
+        if card_labels.chars().count() != ruleset.hand_size() {
+            return Err(Error::invalid(&format!(
+                "Expected a hand of {} cards, found {}",
+                ruleset.hand_size(),
+                card_labels.chars().count()
+            )));
+        }
 
-        match bid.trim().parse::<u64>() {
-            Ok(bid) => Ok(Self {
-                cards,
-                bid,
-                hand_type,
-            }),
-            Err(_) => Err(Error::HandNotParsableError),
+        let cards: Vec<Card> = card_labels
+            .char_indices()
+            .map(|(index, label)| {
+                Card::new(label, ruleset).map_err(|error| error.at_column(index + 1))
+            })
+            .collect::<Result<_>>()?;
+
+        let bid = bid.trim();
+        match bid.parse::<u64>() {
+            Ok(parsed_bid) => Self::new(cards, parsed_bid),
+            Err(_) => Err(Error::invalid_number(bid).at_column_of(string, bid)),
         }
     }
 }
@@ -224,7 +249,7 @@ mod test {
     }
 
     #[test]
-    fn hand_can_be_parsed_from_str() -> Result<(), Error> {
+    fn hand_can_be_parsed_from_str() -> Result<()> {
         let input = "32T3K 765";
         let hand = Hand::from_str(input)?;
         assert!(!hand.cards.is_empty());
@@ -232,7 +257,22 @@ mod test {
     }
 
     #[test]
-    fn can_detect_five_of_a_kind_when_parsing_hand_from_str() -> Result<(), Error> {
+    fn invalid_hands_report_their_column() {
+        let error = Hand::from_str("32X3K 765").unwrap_err();
+        assert_eq!(error.to_string(), "Unexpected character 'X' at column 3");
+
+        let error = Hand::from_str("32T3K 7b5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"7b5\" into a number at column 7"
+        );
+
+        assert!(Hand::from_str("32T3").is_err());
+        assert!(Hand::from_str("32T3 765").is_err());
+    }
+
+    #[test]
+    fn can_detect_five_of_a_kind_when_parsing_hand_from_str() -> Result<()> {
         let input = "AAAAA 765";
         let hand = Hand::from_str(input)?;
         assert!(matches!(hand.hand_type, HandType::FiveOfAKind));
@@ -240,7 +280,7 @@ mod test {
     }
 
     #[test]
-    fn can_detect_four_of_a_kind_when_parsing_hand_from_str() -> Result<(), Error> {
+    fn can_detect_four_of_a_kind_when_parsing_hand_from_str() -> Result<()> {
         let input = "AAAAJ 765";
         let hand = Hand::from_str(input)?;
         assert!(matches!(hand.hand_type, HandType::FourOfAKind));
@@ -248,7 +288,7 @@ mod test {
     }
 
     #[test]
-    fn can_detect_full_house_when_parsing_hand_from_str() -> Result<(), Error> {
+    fn can_detect_full_house_when_parsing_hand_from_str() -> Result<()> {
         let input = "23322 765";
         let hand = Hand::from_str(input)?;
         assert!(matches!(hand.hand_type, HandType::FullHouse));
@@ -256,7 +296,7 @@ mod test {
     }
 
     #[test]
-    fn can_detect_three_of_a_kind_when_parsing_hand_from_str() -> Result<(), Error> {
+    fn can_detect_three_of_a_kind_when_parsing_hand_from_str() -> Result<()> {
         let input = "TTT98 765";
         let hand = Hand::from_str(input)?;
         assert!(matches!(hand.hand_type, HandType::ThreeOfAKind));
@@ -264,7 +304,7 @@ mod test {
     }
 
     #[test]
-    fn can_detect_two_pair_when_parsing_hand_from_str() -> Result<(), Error> {
+    fn can_detect_two_pair_when_parsing_hand_from_str() -> Result<()> {
         let input = "23432 765";
         let hand = Hand::from_str(input)?;
         assert!(matches!(hand.hand_type, HandType::TwoPair));
@@ -272,7 +312,7 @@ mod test {
     }
 
     #[test]
-    fn can_detect_one_pair_when_parsing_hand_from_str() -> Result<(), Error> {
+    fn can_detect_one_pair_when_parsing_hand_from_str() -> Result<()> {
         let input = "A23A4 765";
         let hand = Hand::from_str(input)?;
         assert!(matches!(hand.hand_type, HandType::OnePair));
@@ -280,10 +320,100 @@ mod test {
     }
 
     #[test]
-    fn can_detect_high_card_when_parsing_hand_from_str() -> Result<(), Error> {
+    fn can_detect_high_card_when_parsing_hand_from_str() -> Result<()> {
         let input = "45678 765";
         let hand = Hand::from_str(input)?;
         assert!(matches!(hand.hand_type, HandType::HighCard));
         Ok(())
     }
+
+    #[test]
+    fn jokers_upgrade_hands_when_wild() -> Result<()> {
+        let hand = Hand::parse_with("KTJJT 220", &Ruleset::jokers_wild())?;
+        assert_eq!(hand.hand_type, HandType::FourOfAKind);
+        let hand = Hand::parse_with("JJJJJ 220", &Ruleset::jokers_wild())?;
+        assert_eq!(hand.hand_type, HandType::FiveOfAKind);
+        let hand = Hand::parse_with("KTJJT 220", &Ruleset::standard())?;
+        assert_eq!(hand.hand_type, HandType::TwoPair);
+        Ok(())
+    }
+
+    #[test]
+    fn hand_types_are_classified_by_their_signature() -> Result<()> {
+        // the same type whichever order the labels appear in
+        for input in ["AAKKK 1", "KKKAA 1", "KAKAK 1", "AKKAK 1"] {
+            assert_eq!(Hand::from_str(input)?.hand_type, HandType::FullHouse);
+        }
+        for input in ["AAAAK 1", "KAAAA 1"] {
+            assert_eq!(Hand::from_str(input)?.hand_type, HandType::FourOfAKind);
+        }
+
+        let mut hand_types: Vec<HandType> = [
+            HIGH_CARD,
+            FIVE_OF_A_KIND,
+            TWO_PAIR,
+            FULL_HOUSE,
+            ONE_PAIR,
+            FOUR_OF_A_KIND,
+            THREE_OF_A_KIND,
+        ]
+        .iter()
+        .map(|signature| HandType::from_signature(signature.to_vec()))
+        .collect();
+        hand_types.sort_by(|a, b| b.cmp(a));
+        assert_eq!(
+            hand_types,
+            vec![
+                HandType::FiveOfAKind,
+                HandType::FourOfAKind,
+                HandType::FullHouse,
+                HandType::ThreeOfAKind,
+                HandType::TwoPair,
+                HandType::OnePair,
+                HandType::HighCard,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hands_of_other_sizes_are_classified() -> Result<()> {
+        let ruleset = Ruleset::jokers_wild().with_hand_size(6)?;
+        let two_triples = Hand::parse_with("AAAKKK 1", &ruleset)?;
+        let full_house = Hand::parse_with("AAAKK2 1", &ruleset)?;
+        assert_eq!(two_triples.hand_type, HandType::Other(vec![3, 3]));
+        assert_eq!(full_house.hand_type, HandType::Other(vec![3, 2, 1]));
+        assert!(two_triples > full_house);
+
+        let upgraded = Hand::parse_with("AAJKK2 1", &ruleset)?;
+        assert_eq!(upgraded.hand_type, HandType::Other(vec![3, 2, 1]));
+        let all_wild = Hand::parse_with("JJJJJJ 1", &ruleset)?;
+        assert_eq!(all_wild.hand_type, HandType::Other(vec![6]));
+
+        let ruleset = Ruleset::standard().with_hand_size(3)?;
+        assert_eq!(
+            Hand::parse_with("AKA 1", &ruleset)?.hand_type,
+            HandType::Other(vec![2, 1])
+        );
+        assert_eq!(
+            Hand::parse_with("AKQJT 1", &ruleset)
+                .unwrap_err()
+                .to_string(),
+            "Expected a hand of 3 cards, found 5"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hand_types_display_their_name() -> Result<()> {
+        assert_eq!(HandType::FullHouse.to_string(), "Full house");
+        assert_eq!(
+            HandType::from_signature(vec![3, 3]).to_string(),
+            "Groups of 3-3"
+        );
+
+        let hand = Hand::parse_with("KTJJT 220", &Ruleset::jokers_wild())?;
+        assert_eq!(hand.get_hand_type_without_wildcards(), HandType::TwoPair);
+        Ok(())
+    }
 }
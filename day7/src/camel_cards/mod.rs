@@ -1,4 +1,6 @@
 pub mod card;
-pub mod error;
+pub mod explain;
 pub mod game;
 pub mod hand;
+pub mod ruleset;
+pub mod simulator;
@@ -0,0 +1,267 @@
+use std::fs::read_to_string;
+use std::path::Path;
+use std::str::FromStr;
+
+use common::error::{Error, Result};
+
+const STANDARD_RANKS: &str = "AKQJT98765432";
+const STANDARD_HAND_SIZE: usize = 5;
+
+// how wildcards compare against other cards when hands of the same type are tied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WildTieBreak {
+    Lowest,
+    Rank,
+}
+
+impl FromStr for WildTieBreak {
+    type Err = Error;
+    fn from_str(string: &str) -> Result<Self> {
+        match string {
+            "lowest" => Ok(Self::Lowest),
+            "rank" => Ok(Self::Rank),
+            _ => Err(Error::invalid(&format!(
+                "Wild tie break must be either lowest or rank, got {}",
+                string
+            ))),
+        }
+    }
+}
+
+// the variant of camel cards being played, deciding how cards are ranked & which are wild
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ruleset {
+    // labels from strongest to weakest
+    ranks: Vec<char>,
+    wild_labels: Vec<char>,
+    wild_tie_break: WildTieBreak,
+    hand_size: usize,
+}
+
+impl Ruleset {
+    pub fn new(ranks: &str, wild_labels: &str, wild_tie_break: WildTieBreak) -> Result<Self> {
+        let ranks: Vec<char> = ranks.chars().collect();
+        if ranks.is_empty() {
+            return Err(Error::missing("at least one ranked label"));
+        }
+        for (index, label) in ranks.iter().enumerate() {
+            if ranks[..index].contains(label) {
+                return Err(Error::invalid(&format!(
+                    "Label {:?} is ranked twice",
+                    label
+                )));
+            }
+        }
+
+        let wild_labels: Vec<char> = wild_labels.chars().collect();
+        if let Some(label) = wild_labels.iter().find(|label| !ranks.contains(label)) {
+            return Err(Error::invalid(&format!(
+                "Wild label {:?} isn't a ranked label",
+                label
+            )));
+        }
+
+        Ok(Self {
+            ranks,
+            wild_labels,
+            wild_tie_break,
+            hand_size: STANDARD_HAND_SIZE,
+        })
+    }
+
+    pub fn with_hand_size(self, hand_size: usize) -> Result<Self> {
+        if hand_size == 0 {
+            return Err(Error::invalid("Hands must hold at least one card"));
+        }
+        Ok(Self { hand_size, ..self })
+    }
+
+    pub fn standard() -> Self {
+        Self {
+            ranks: STANDARD_RANKS.chars().collect(),
+            wild_labels: vec![],
+            wild_tie_break: WildTieBreak::Lowest,
+            hand_size: STANDARD_HAND_SIZE,
+        }
+    }
+
+    // 'J' cards act as whichever card makes the strongest hand, but are the weakest card alone
+    pub fn jokers_wild() -> Self {
+        Self {
+            wild_labels: vec!['J'],
+            ..Self::standard()
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        match read_to_string(path) {
+            Ok(contents) => Self::from_str(&contents),
+            Err(_) => Err(Error::Input(format!(
+                "Couldn't find file: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    pub fn ranks(&self) -> &[char] {
+        &self.ranks
+    }
+
+    pub fn wild_labels(&self) -> &[char] {
+        &self.wild_labels
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn is_wild(&self, label: char) -> bool {
+        self.wild_labels.contains(&label)
+    }
+
+    // strength of a label when breaking ties, where the weakest label has a strength of 0
+    pub fn strength(&self, label: char) -> Option<usize> {
+        let position = self.ranks.iter().position(|ranked| *ranked == label)?;
+        let rank_strength = self.ranks.len() - position;
+        match self.wild_tie_break {
+            WildTieBreak::Lowest if self.is_wild(label) => Some(0),
+            _ => Some(rank_strength),
+        }
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+// a ruleset written as `key = value` lines, e.g.
+//
+// ranks = AKQJT98765432
+// wild = J
+// wild_tie_break = lowest
+// hand_size = 5
+impl FromStr for Ruleset {
+    type Err = Error;
+    fn from_str(string: &str) -> Result<Self> {
+        let mut ranks = String::from(STANDARD_RANKS);
+        let mut wild_labels = String::new();
+        let mut wild_tie_break = WildTieBreak::Lowest;
+        let mut hand_size = STANDARD_HAND_SIZE;
+
+        for (line_index, line) in string.lines().enumerate() {
+            let setting = match line.split_once('#') {
+                Some((setting, _)) => setting,
+                None => line,
+            };
+            if setting.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = setting
+                .split_once('=')
+                .ok_or(Error::missing("'=' between setting and value").at_line(line_index + 1))?;
+            let value = value.trim();
+
+            match key.trim() {
+                "ranks" => ranks = value.to_string(),
+                // labels either run together or are separated by commas, e.g. `JQ` or `J, Q`
+                "wild" => wild_labels = value.split(',').map(str::trim).collect(),
+                "wild_tie_break" => {
+                    wild_tie_break = WildTieBreak::from_str(value)
+                        .map_err(|error| error.at_line(line_index + 1).at_column_of(line, value))?
+                }
+                "hand_size" => {
+                    hand_size = value.parse::<usize>().map_err(|_| {
+                        Error::invalid_number(value)
+                            .at_line(line_index + 1)
+                            .at_column_of(line, value)
+                    })?
+                }
+                unknown => {
+                    return Err(Error::invalid(&format!("Unknown setting {}", unknown))
+                        .at_line(line_index + 1)
+                        .at_column_of(line, unknown))
+                }
+            }
+        }
+
+        Self::new(&ranks, &wild_labels, wild_tie_break)?.with_hand_size(hand_size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rulesets_load_from_config_files() -> Result<()> {
+        let rules_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("rules");
+        assert_eq!(
+            Ruleset::load(&rules_dir.join("standard.txt"))?,
+            Ruleset::standard()
+        );
+        assert_eq!(
+            Ruleset::load(&rules_dir.join("jokers-wild.txt"))?,
+            Ruleset::jokers_wild()
+        );
+
+        let house_rules = Ruleset::load(&rules_dir.join("deuces-wild.txt"))?;
+        assert_eq!(house_rules.wild_labels(), &['2']);
+        assert_eq!(house_rules.strength('2'), Some(1));
+        assert_eq!(house_rules.strength('A'), Some(13));
+        assert_eq!(house_rules.hand_size(), 5);
+
+        let six_card_rules = Ruleset::from_str("wild = J\nhand_size = 6")?;
+        assert_eq!(six_card_rules.hand_size(), 6);
+
+        for wild in ["JQ", "J,Q", "J, Q", " J ,Q "] {
+            let ruleset = Ruleset::from_str(&format!("wild = {}", wild))?;
+            assert_eq!(ruleset.wild_labels(), &['J', 'Q']);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn wildcards_tie_break_as_configured() -> Result<()> {
+        let ruleset = Ruleset::jokers_wild();
+        assert_eq!(ruleset.strength('J'), Some(0));
+        assert_eq!(ruleset.strength('2'), Some(1));
+
+        let ruleset = Ruleset::new(STANDARD_RANKS, "J", WildTieBreak::Rank)?;
+        assert_eq!(ruleset.strength('J'), Some(10));
+        assert_eq!(ruleset.strength('X'), None);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_rulesets_are_rejected() {
+        let error = Ruleset::from_str("ranks = AKQ\nwild = J").unwrap_err();
+        assert_eq!(error.to_string(), "Wild label 'J' isn't a ranked label");
+
+        let error = Ruleset::from_str("ranks = AKQA").unwrap_err();
+        assert_eq!(error.to_string(), "Label 'A' is ranked twice");
+
+        let error = Ruleset::from_str("# house rules\nwilds = J").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unknown setting wilds at line 2, column 1"
+        );
+
+        let error = Ruleset::from_str("wild_tie_break = highest").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Wild tie break must be either lowest or rank, got highest at line 1, column 18"
+        );
+
+        let error = Ruleset::from_str("hand_size = 0").unwrap_err();
+        assert_eq!(error.to_string(), "Hands must hold at least one card");
+
+        let error = Ruleset::from_str("wild J").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected '=' between setting and value at line 1"
+        );
+    }
+}
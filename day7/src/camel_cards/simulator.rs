@@ -0,0 +1,299 @@
+use std::fmt;
+
+use common::error::{Error, Result};
+
+use crate::camel_cards::card::Card;
+use crate::camel_cards::game::Game;
+use crate::camel_cards::hand::{Hand, HandType};
+use crate::camel_cards::ruleset::Ruleset;
+
+// xorshift64*, small & seedable so simulations can be repeated exactly
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // the state must never be zero, so the seed is scrambled with splitmix64 first
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        state ^= state >> 31;
+        Self {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // a number from 0 up to, but not including, the bound
+    pub fn below(&mut self, bound: u64) -> u64 {
+        // rejects the uneven remainder at the top of the range so every number is as likely
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let number = self.next_u64();
+            if number < limit {
+                return number % bound;
+            }
+        }
+    }
+}
+
+// the labels hands are dealt from, each hand being dealt from a freshly shuffled deck
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    labels: Vec<char>,
+}
+
+impl Deck {
+    pub fn new(labels: Vec<char>) -> Self {
+        Self { labels }
+    }
+
+    // every label the ruleset ranks, each repeated the given number of times
+    pub fn from_ruleset(ruleset: &Ruleset, copies: usize) -> Self {
+        Self {
+            labels: ruleset
+                .ranks()
+                .iter()
+                .flat_map(|label| std::iter::repeat_n(*label, copies))
+                .collect(),
+        }
+    }
+
+    pub fn labels(&self) -> &[char] {
+        &self.labels
+    }
+
+    fn deal(&self, rng: &mut Rng, hand_size: usize) -> Vec<char> {
+        let mut labels = self.labels.clone();
+        // a partial Fisher-Yates shuffle, only shuffling the cards being dealt
+        for index in 0..hand_size {
+            let swap_index = index + rng.below((labels.len() - index) as u64) as usize;
+            labels.swap(index, swap_index);
+        }
+        labels.truncate(hand_size);
+        labels
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationOptions {
+    pub ruleset: Ruleset,
+    pub deck: Deck,
+    pub hand_count: usize,
+    // bids are dealt uniformly from 1 up to & including the maximum bid
+    pub max_bid: u64,
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationReport {
+    pub hand_count: usize,
+    // each hand type dealt alongside how many times, strongest first
+    pub hand_type_counts: Vec<(HandType, usize)>,
+    pub total_bid: u64,
+    pub total_winnings: u64,
+    // how many hands held wildcards, & how many of those were upgraded by them
+    pub wild_hand_count: usize,
+    pub upgraded_hand_count: usize,
+}
+
+impl SimulationReport {
+    pub fn expected_winnings_per_bid(&self) -> f64 {
+        if self.total_bid == 0 {
+            return 0.0;
+        }
+        self.total_winnings as f64 / self.total_bid as f64
+    }
+
+    pub fn upgrade_frequency(&self) -> f64 {
+        if self.hand_count == 0 {
+            return 0.0;
+        }
+        self.upgraded_hand_count as f64 / self.hand_count as f64
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Hands dealt: {}", self.hand_count)?;
+        for (hand_type, count) in self.hand_type_counts.iter() {
+            writeln!(
+                f,
+                "  {}: {} ({:.2}%)",
+                hand_type,
+                count,
+                *count as f64 * 100.0 / self.hand_count as f64
+            )?;
+        }
+        writeln!(
+            f,
+            "Expected winnings per bid: {:.4}",
+            self.expected_winnings_per_bid()
+        )?;
+        write!(
+            f,
+            "Hands upgraded by wildcards: {} of {} holding wildcards ({:.2}% of all hands)",
+            self.upgraded_hand_count,
+            self.wild_hand_count,
+            self.upgrade_frequency() * 100.0
+        )
+    }
+}
+
+fn deal_hands(options: &SimulationOptions) -> Result<Vec<Hand>> {
+    let hand_size = options.ruleset.hand_size();
+    if options.deck.labels.len() < hand_size {
+        return Err(Error::invalid(&format!(
+            "A deck of {} cards can't deal hands of {}",
+            options.deck.labels.len(),
+            hand_size
+        )));
+    }
+    if options.max_bid == 0 {
+        return Err(Error::invalid("Maximum bid must be at least 1"));
+    }
+
+    let mut rng = Rng::new(options.seed);
+    (0..options.hand_count)
+        .map(|_| {
+            let cards: Vec<Card> = options
+                .deck
+                .deal(&mut rng, hand_size)
+                .into_iter()
+                .map(|label| Card::new(label, &options.ruleset))
+                .collect::<Result<_>>()?;
+            let bid = rng.below(options.max_bid) + 1;
+            Hand::new(cards, bid)
+        })
+        .collect()
+}
+
+pub fn simulate(options: &SimulationOptions) -> Result<SimulationReport> {
+    let game = Game::new(deal_hands(options)?);
+
+    let mut hand_type_counts: Vec<(HandType, usize)> = vec![];
+    for hand in game.hands.iter() {
+        // hands are ranked strongest first, so each type's hands are next to each other
+        match hand_type_counts.last_mut() {
+            Some((hand_type, count)) if *hand_type == hand.hand_type => *count += 1,
+            _ => hand_type_counts.push((hand.hand_type.clone(), 1)),
+        }
+    }
+
+    let wild_hands: Vec<&Hand> = game
+        .hands
+        .iter()
+        .filter(|hand| hand.cards.iter().any(|card| card.wild))
+        .collect();
+    let upgraded_hand_count = wild_hands
+        .iter()
+        .filter(|hand| hand.hand_type > hand.get_hand_type_without_wildcards())
+        .count();
+
+    Ok(SimulationReport {
+        hand_count: game.hands.len(),
+        hand_type_counts,
+        total_bid: game.hands.iter().map(|hand| hand.bid).sum(),
+        total_winnings: game.calculate_winnings(),
+        wild_hand_count: wild_hands.len(),
+        upgraded_hand_count,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn options(ruleset: Ruleset, seed: u64) -> SimulationOptions {
+        SimulationOptions {
+            deck: Deck::from_ruleset(&ruleset, 4),
+            ruleset,
+            hand_count: 1000,
+            max_bid: 1000,
+            seed,
+        }
+    }
+
+    #[test]
+    fn rng_is_repeatable_and_bounded() {
+        let mut rng = Rng::new(7);
+        let numbers: Vec<u64> = (0..100).map(|_| rng.below(6)).collect();
+        assert!(numbers.iter().all(|number| *number < 6));
+        assert!((0..6).all(|number| numbers.contains(&number)));
+
+        let mut rng = Rng::new(7);
+        let repeated: Vec<u64> = (0..100).map(|_| rng.below(6)).collect();
+        assert_eq!(numbers, repeated);
+        assert_ne!(Rng::new(0).next_u64(), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    fn simulations_are_repeatable_with_the_same_seed() -> Result<()> {
+        let report = simulate(&options(Ruleset::jokers_wild(), 2023))?;
+        assert_eq!(report, simulate(&options(Ruleset::jokers_wild(), 2023))?);
+        assert_ne!(report, simulate(&options(Ruleset::jokers_wild(), 2024))?);
+
+        assert_eq!(report.hand_count, 1000);
+        assert_eq!(
+            report
+                .hand_type_counts
+                .iter()
+                .map(|(_, count)| count)
+                .sum::<usize>(),
+            1000
+        );
+        assert!(report
+            .hand_type_counts
+            .windows(2)
+            .all(|pair| pair[0].0 > pair[1].0));
+        Ok(())
+    }
+
+    #[test]
+    fn wildcards_upgrade_hands() -> Result<()> {
+        let standard = simulate(&options(Ruleset::standard(), 1))?;
+        assert_eq!(standard.wild_hand_count, 0);
+        assert_eq!(standard.upgraded_hand_count, 0);
+
+        // every hand holding a joker is upgraded by it
+        let jokers_wild = simulate(&options(Ruleset::jokers_wild(), 1))?;
+        assert!(jokers_wild.wild_hand_count > 0);
+        assert_eq!(jokers_wild.upgraded_hand_count, jokers_wild.wild_hand_count);
+
+        // roughly 1 - (48 choose 5) / (52 choose 5) of hands hold a joker
+        let frequency = jokers_wild.upgrade_frequency();
+        assert!(frequency > 0.29 && frequency < 0.39, "{frequency}");
+        Ok(())
+    }
+
+    #[test]
+    fn winnings_per_bid_average_the_rank() -> Result<()> {
+        // bids matching every hand leaves the mean rank, (n + 1) / 2, per bid
+        let report = simulate(&SimulationOptions {
+            max_bid: 1,
+            ..options(Ruleset::standard(), 5)
+        })?;
+        assert_eq!(report.total_bid, 1000);
+        assert_eq!(report.expected_winnings_per_bid(), 500.5);
+        Ok(())
+    }
+
+    #[test]
+    fn simulations_reject_decks_too_small_to_deal_from() {
+        let ruleset = Ruleset::standard();
+        let error = simulate(&SimulationOptions {
+            deck: Deck::new(vec!['A', 'K']),
+            ..options(ruleset, 1)
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "A deck of 2 cards can't deal hands of 5");
+    }
+}
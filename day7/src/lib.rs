@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use common::error::Result;
+use common::{ReportFormat, Solution};
+
+pub mod camel_cards;
+
+use camel_cards::explain;
+use camel_cards::game::Game;
+use camel_cards::ruleset::Ruleset;
+
+pub struct Day7;
+
+fn process(input: &str, ruleset: &Ruleset) -> Result<u64> {
+    let game = Game::parse_with(input, ruleset)?;
+    Ok(game.calculate_winnings())
+}
+
+fn explain_ranks(input: &str, ruleset: &Ruleset, format: ReportFormat) -> Result<String> {
+    let game = Game::parse_with(input, ruleset)?;
+    let explanations = explain::explain(&game, ruleset);
+    Ok(match format {
+        ReportFormat::Table => explain::to_table(&explanations),
+        ReportFormat::Json => explain::to_json(&explanations),
+    })
+}
+
+impl Solution for Day7 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        process(input, &Ruleset::standard()).map(|result| result.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        process(input, &Ruleset::jokers_wild()).map(|result| result.to_string())
+    }
+
+    fn explain_part1(&self, input: &str, format: ReportFormat) -> Result<String> {
+        explain_ranks(input, &Ruleset::standard(), format)
+    }
+
+    fn explain_part2(&self, input: &str, format: ReportFormat) -> Result<String> {
+        explain_ranks(input, &Ruleset::jokers_wild(), format)
+    }
+
+    fn solve_with_rules(&self, input: &str, rules: &str) -> Result<String> {
+        process(input, &Ruleset::from_str(rules)?).map(|result| result.to_string())
+    }
+
+    fn explain_with_rules(&self, input: &str, rules: &str, format: ReportFormat) -> Result<String> {
+        explain_ranks(input, &Ruleset::from_str(rules)?, format)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rules_can_be_loaded_at_runtime() -> Result<()> {
+        let input = include_str!("./example.txt");
+        assert_eq!(
+            Day7.solve_with_rules(input, include_str!("../rules/jokers-wild.txt"))?,
+            Day7.part2(input)?
+        );
+        assert_eq!(
+            Day7.solve_with_rules(input, include_str!("../rules/standard.txt"))?,
+            Day7.part1(input)?
+        );
+
+        let error = Day7.solve_with_rules(input, "wilds = J").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unknown setting wilds at line 1, column 1"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use crate::node::{Instruction, Instructions, Network, NodeId};
+
+// the most steps listed on a walked edge before the rest are only counted
+const MAX_LISTED_STEPS: usize = 3;
+
+pub struct ExportOptions<'a> {
+    pub starts: Vec<NodeId>,
+    pub ends: Vec<NodeId>,
+    // instructions to walk from each start, overlaying the steps each edge was taken at
+    pub walk: Option<&'a Instructions>,
+}
+
+struct Edge {
+    from: usize,
+    to: usize,
+    instruction: Instruction,
+    // 1-based steps the edge was taken at during the walk
+    steps: Vec<u64>,
+}
+
+impl Edge {
+    fn label(&self) -> String {
+        let instruction = match self.instruction {
+            Instruction::Left => "L",
+            Instruction::Right => "R",
+        };
+        if self.steps.is_empty() {
+            return instruction.to_string();
+        }
+
+        let mut steps: Vec<String> = self
+            .steps
+            .iter()
+            .take(MAX_LISTED_STEPS)
+            .map(|step| step.to_string())
+            .collect();
+        if self.steps.len() > MAX_LISTED_STEPS {
+            steps.push(format!("... ({} times)", self.steps.len()));
+        }
+        format!("{} @ {}", instruction, steps.join(", "))
+    }
+}
+
+fn is_listed(network: &Network, index: usize, node_ids: &[NodeId]) -> bool {
+    node_ids.contains(&network.ids()[index])
+}
+
+// walks from each start until reaching an end, going round in circles or leaving the network
+fn walk(
+    network: &Network,
+    instructions: &Instructions,
+    options: &ExportOptions,
+) -> HashMap<(usize, Instruction), Vec<u64>> {
+    let mut steps_by_edge: HashMap<(usize, Instruction), Vec<u64>> = HashMap::new();
+
+    for start in options.starts.iter() {
+        let Ok(start) = network.defined_index_of(start) else {
+            continue;
+        };
+        let mut walk = network.walk(instructions, start);
+
+        while !is_listed(network, walk.index(), &options.ends) {
+            let Some(step) = walk.next() else {
+                break;
+            };
+            steps_by_edge
+                .entry((step.from, step.instruction))
+                .or_default()
+                .push(step.step);
+        }
+    }
+
+    steps_by_edge
+}
+
+fn get_edges(network: &Network, options: &ExportOptions) -> Vec<Edge> {
+    let mut steps_by_edge = match options.walk {
+        Some(instructions) => walk(network, instructions, options),
+        None => HashMap::new(),
+    };
+
+    (0..network.ids().len())
+        .filter(|index| network.is_defined(*index))
+        .flat_map(|from| {
+            [Instruction::Left, Instruction::Right]
+                .into_iter()
+                .filter_map(move |instruction| {
+                    let to = network.get_next_index(from, instruction).ok()?;
+                    Some((from, to, instruction))
+                })
+        })
+        .map(|(from, to, instruction)| Edge {
+            from,
+            to,
+            instruction,
+            steps: steps_by_edge
+                .remove(&(from, instruction))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl Network {
+    // a Graphviz digraph, with starts in green, ends in red & undefined neighbours dashed
+    pub fn to_dot(&self, options: &ExportOptions) -> String {
+        let mut lines = vec![String::from("digraph network {")];
+
+        for (index, node_id) in self.ids().iter().enumerate() {
+            let style = if is_listed(self, index, &options.starts) {
+                " [style=filled, fillcolor=palegreen]"
+            } else if is_listed(self, index, &options.ends) {
+                " [style=filled, fillcolor=lightcoral]"
+            } else if !self.is_defined(index) {
+                " [style=dashed]"
+            } else {
+                ""
+            };
+            lines.push(format!("    {}{};", dot_string(node_id.as_str()), style));
+        }
+
+        for edge in get_edges(self, options) {
+            let walked = if edge.steps.is_empty() {
+                ""
+            } else {
+                ", color=blue, penwidth=2"
+            };
+            lines.push(format!(
+                "    {} -> {} [label={}{}];",
+                dot_string(self.ids()[edge.from].as_str()),
+                dot_string(self.ids()[edge.to].as_str()),
+                dot_string(&edge.label()),
+                walked
+            ));
+        }
+
+        lines.push(String::from("}"));
+        lines.join("\n")
+    }
+
+    // a Mermaid flowchart, where nodes are named by index as ids such as `end` are reserved
+    pub fn to_mermaid(&self, options: &ExportOptions) -> String {
+        let mut lines = vec![String::from("flowchart LR")];
+
+        for (index, node_id) in self.ids().iter().enumerate() {
+            lines.push(format!("    n{}[\"{}\"]", index, node_id.as_str()));
+        }
+
+        let edges = get_edges(self, options);
+        for edge in edges.iter() {
+            lines.push(format!(
+                "    n{} -->|\"{}\"| n{}",
+                edge.from,
+                edge.label(),
+                edge.to
+            ));
+        }
+
+        lines.push(String::from("    classDef startNode fill:#9f9"));
+        lines.push(String::from("    classDef endNode fill:#f99"));
+        lines.push(String::from(
+            "    classDef undefinedNode stroke-dasharray:4",
+        ));
+        for (index, _) in self.ids().iter().enumerate() {
+            let class = if is_listed(self, index, &options.starts) {
+                "startNode"
+            } else if is_listed(self, index, &options.ends) {
+                "endNode"
+            } else if !self.is_defined(index) {
+                "undefinedNode"
+            } else {
+                continue;
+            };
+            lines.push(format!("    class n{} {}", index, class));
+        }
+
+        for (edge_index, edge) in edges.iter().enumerate() {
+            if !edge.steps.is_empty() {
+                lines.push(format!(
+                    "    linkStyle {} stroke:#00f,stroke-width:2px",
+                    edge_index
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::error::Result;
+
+    fn options(walk: Option<&Instructions>) -> ExportOptions<'_> {
+        ExportOptions {
+            starts: vec![NodeId::new("AAA")],
+            ends: vec![NodeId::new("ZZZ")],
+            walk,
+        }
+    }
+
+    #[test]
+    fn networks_export_as_dot() -> Result<()> {
+        let (instructions, network) = crate::parse(include_str!("./example.txt"))?;
+        assert_eq!(
+            network.to_dot(&options(None)),
+            "digraph network {
+    \"AAA\" [style=filled, fillcolor=palegreen];
+    \"BBB\";
+    \"ZZZ\" [style=filled, fillcolor=lightcoral];
+    \"AAA\" -> \"BBB\" [label=\"L\"];
+    \"AAA\" -> \"BBB\" [label=\"R\"];
+    \"BBB\" -> \"AAA\" [label=\"L\"];
+    \"BBB\" -> \"ZZZ\" [label=\"R\"];
+    \"ZZZ\" -> \"ZZZ\" [label=\"L\"];
+    \"ZZZ\" -> \"ZZZ\" [label=\"R\"];
+}"
+        );
+
+        let dot = network.to_dot(&options(Some(&instructions)));
+        assert!(dot.contains("\"AAA\" -> \"BBB\" [label=\"L @ 1, 5\", color=blue, penwidth=2];"));
+        assert!(dot.contains("\"BBB\" -> \"ZZZ\" [label=\"R @ 6\", color=blue, penwidth=2];"));
+        assert!(dot.contains("\"ZZZ\" -> \"ZZZ\" [label=\"L\"];"));
+        Ok(())
+    }
+
+    #[test]
+    fn networks_export_as_mermaid() -> Result<()> {
+        let (instructions, network) = crate::parse(include_str!("./example.txt"))?;
+        assert_eq!(
+            network.to_mermaid(&options(Some(&instructions))),
+            "flowchart LR
+    n0[\"AAA\"]
+    n1[\"BBB\"]
+    n2[\"ZZZ\"]
+    n0 -->|\"L @ 1, 5\"| n1
+    n0 -->|\"R @ 3\"| n1
+    n1 -->|\"L @ 2, 4\"| n0
+    n1 -->|\"R @ 6\"| n2
+    n2 -->|\"L\"| n2
+    n2 -->|\"R\"| n2
+    classDef startNode fill:#9f9
+    classDef endNode fill:#f99
+    classDef undefinedNode stroke-dasharray:4
+    class n0 startNode
+    class n2 endNode
+    linkStyle 0 stroke:#00f,stroke-width:2px
+    linkStyle 1 stroke:#00f,stroke-width:2px
+    linkStyle 2 stroke:#00f,stroke-width:2px
+    linkStyle 3 stroke:#00f,stroke-width:2px"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn long_walks_count_rather_than_list_steps() {
+        let edge = Edge {
+            from: 0,
+            to: 1,
+            instruction: Instruction::Right,
+            steps: vec![1, 3, 5, 7, 9],
+        };
+        assert_eq!(edge.label(), "R @ 1, 3, 5, ... (5 times)");
+    }
+}
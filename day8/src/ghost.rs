@@ -0,0 +1,301 @@
+use common::error::{Error, Result};
+
+use crate::node::{Instructions, Network, NodeId};
+use crate::walk::WalkEnd;
+
+const GHOST_START_SUFFIX: char = 'A';
+const GHOST_END_SUFFIX: char = 'Z';
+
+// a walker's path settles into a loop once a pass of the instructions starts on a node one
+// already started on, so the steps it sits on an end node can be described without walking
+// forever
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalkCycle {
+    // the step of the first pass within the loop & how many steps it takes to come back around
+    pub cycle_start: u128,
+    pub cycle_length: u128,
+    // steps on an end node before the loop starts, which only happen once
+    pub lead_in_ends: Vec<u128>,
+    // steps on an end node within the first pass of the loop, repeating every cycle length
+    pub cycle_ends: Vec<u128>,
+}
+
+impl WalkCycle {
+    pub fn find(
+        network: &Network,
+        instructions: &Instructions,
+        start: &NodeId,
+        is_end: impl Fn(&NodeId) -> bool,
+    ) -> Result<Self> {
+        let mut walk = network.walk(instructions, network.defined_index_of(start)?);
+        let mut ends: Vec<u128> = vec![];
+
+        loop {
+            if is_end(&network.ids()[walk.index()]) {
+                ends.push(walk.steps() as u128);
+            }
+            if walk.next().is_none() {
+                break;
+            }
+        }
+
+        let cycle_start = match walk.end() {
+            Some(WalkEnd::Circling { cycle_start }) => *cycle_start as u128,
+            Some(WalkEnd::Dangling(error)) => return Err(error.clone()),
+            None => return Err(Error::missing("at least one instruction")),
+        };
+        // the end the loop comes back around to is the first of its next pass
+        let cycle_end = walk.steps() as u128;
+        ends.retain(|end_step| *end_step < cycle_end);
+        let (lead_in_ends, cycle_ends) = ends.iter().partition(|end_step| **end_step < cycle_start);
+
+        Ok(Self {
+            cycle_start,
+            cycle_length: cycle_end - cycle_start,
+            lead_in_ends,
+            cycle_ends,
+        })
+    }
+
+    pub fn is_end_at(&self, step: u128) -> bool {
+        self.lead_in_ends.contains(&step)
+            || self.cycle_ends.iter().any(|end_step| {
+                step >= *end_step && (step - end_step).is_multiple_of(self.cycle_length)
+            })
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn overflow_error() -> Error {
+    Error::invalid("Ghost walk needs more steps than can be counted")
+}
+
+// steps sitting at `remainder` within every `modulus` steps, from the `earliest` step on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Congruence {
+    remainder: u128,
+    modulus: u128,
+    earliest: u128,
+}
+
+impl Congruence {
+    // (a * b) % modulus without overflowing, by doubling
+    fn multiply(mut a: u128, mut b: u128, modulus: u128) -> u128 {
+        let mut product = 0;
+        a %= modulus;
+        while b > 0 {
+            if b & 1 == 1 {
+                product = (product + a) % modulus;
+            }
+            a = (a * 2) % modulus;
+            b >>= 1;
+        }
+        product
+    }
+
+    // inverse of `a` modulo a `modulus` it shares no factors with
+    fn inverse(a: u128, modulus: u128) -> u128 {
+        let (mut old_r, mut r) = (a as i128 % modulus as i128, modulus as i128);
+        let (mut old_s, mut s) = (1_i128, 0_i128);
+        while r != 0 {
+            let quotient = old_r / r;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+        }
+        old_s.rem_euclid(modulus as i128) as u128
+    }
+
+    // the chinese remainder theorem, for moduli which may share factors
+    fn combine(&self, other: &Congruence) -> Result<Option<Congruence>> {
+        let divisor = gcd(self.modulus, other.modulus);
+        let difference =
+            (other.remainder + other.modulus - self.remainder % other.modulus) % other.modulus;
+        if !difference.is_multiple_of(divisor) {
+            return Ok(None);
+        }
+
+        let other_modulus = other.modulus / divisor;
+        let modulus = self
+            .modulus
+            .checked_mul(other_modulus)
+            .ok_or_else(overflow_error)?;
+        let multiple = Self::multiply(
+            difference / divisor,
+            Self::inverse(
+                self.modulus / divisor % other_modulus.max(1),
+                other_modulus.max(1),
+            ),
+            other_modulus.max(1),
+        );
+        let remainder =
+            (self.remainder + Self::multiply(self.modulus % modulus, multiple, modulus)) % modulus;
+
+        Ok(Some(Congruence {
+            remainder,
+            modulus,
+            earliest: self.earliest.max(other.earliest),
+        }))
+    }
+
+    fn first_step(&self) -> Result<u128> {
+        if self.remainder >= self.earliest {
+            return Ok(self.remainder);
+        }
+        let cycles = (self.earliest - self.remainder).div_ceil(self.modulus);
+        cycles
+            .checked_mul(self.modulus)
+            .and_then(|steps| steps.checked_add(self.remainder))
+            .ok_or_else(overflow_error)
+    }
+}
+
+fn lcm(cycles: &[WalkCycle]) -> Result<u128> {
+    cycles.iter().try_fold(1, |multiple: u128, cycle| {
+        (multiple / gcd(multiple, cycle.cycle_length))
+            .checked_mul(cycle.cycle_length)
+            .ok_or_else(overflow_error)
+    })
+}
+
+// the first step every walker sits on an end node at once
+pub fn find_first_shared_end(cycles: &[WalkCycle]) -> Result<Option<u128>> {
+    // each walker ending exactly once per loop, at the end of the loop, lines up every
+    // lowest common multiple of the loop lengths
+    if cycles
+        .iter()
+        .all(|cycle| cycle.lead_in_ends.is_empty() && cycle.cycle_ends == vec![cycle.cycle_length])
+    {
+        return lcm(cycles).map(Some);
+    }
+
+    // any walker's lead in end shared by every other walker
+    let mut first_shared_end = cycles
+        .iter()
+        .flat_map(|cycle| cycle.lead_in_ends.iter())
+        .filter(|step| cycles.iter().all(|cycle| cycle.is_end_at(**step)))
+        .min()
+        .copied();
+
+    // otherwise every walker is within its loop, so pick an end from each & line them up
+    let mut congruences: Vec<Congruence> = vec![Congruence {
+        remainder: 0,
+        modulus: 1,
+        earliest: 0,
+    }];
+    for cycle in cycles {
+        let mut combined: Vec<Congruence> = vec![];
+        for congruence in congruences.iter() {
+            for end_step in cycle.cycle_ends.iter() {
+                let end = Congruence {
+                    remainder: end_step % cycle.cycle_length,
+                    modulus: cycle.cycle_length,
+                    earliest: *end_step,
+                };
+                if let Some(congruence) = congruence.combine(&end)? {
+                    combined.push(congruence);
+                }
+            }
+        }
+        congruences = combined;
+    }
+
+    for congruence in congruences {
+        let step = congruence.first_step()?;
+        first_shared_end = Some(first_shared_end.map_or(step, |shared| shared.min(step)));
+    }
+
+    Ok(first_shared_end)
+}
+
+// every ghost starts on a node ending in A, moving together until all are on nodes ending in Z
+pub fn calculate_ghost_steps(network: &Network, instructions: &Instructions) -> Result<u128> {
+    let mut starts: Vec<&NodeId> = network
+        .node_ids()
+        .filter(|node_id| node_id.as_str().ends_with(GHOST_START_SUFFIX))
+        .collect();
+    starts.sort_by_key(|node_id| node_id.as_str());
+    if starts.is_empty() {
+        return Err(Error::missing("a node ending in A"));
+    }
+
+    let cycles: Vec<WalkCycle> = starts
+        .iter()
+        .map(|start| {
+            WalkCycle::find(network, instructions, start, |node_id| {
+                node_id.as_str().ends_with(GHOST_END_SUFFIX)
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    find_first_shared_end(&cycles)?.ok_or(Error::invalid(
+        "Ghosts never sit on nodes ending in Z at the same time",
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cycle(
+        cycle_start: u128,
+        cycle_length: u128,
+        lead_in_ends: &[u128],
+        cycle_ends: &[u128],
+    ) -> WalkCycle {
+        WalkCycle {
+            cycle_start,
+            cycle_length,
+            lead_in_ends: lead_in_ends.to_vec(),
+            cycle_ends: cycle_ends.to_vec(),
+        }
+    }
+
+    #[test]
+    fn walkers_settle_into_cycles() -> Result<()> {
+        let (instructions, network) = crate::parse(include_str!("./example-ghosts.txt"))?;
+        let walk_cycle =
+            WalkCycle::find(&network, &instructions, &NodeId::new("22A"), |node_id| {
+                node_id.as_str().ends_with('Z')
+            })?;
+        assert_eq!(walk_cycle, cycle(2, 6, &[], &[3, 6]));
+        assert!(walk_cycle.is_end_at(9));
+        assert!(!walk_cycle.is_end_at(10));
+        Ok(())
+    }
+
+    #[test]
+    fn ghosts_line_up_on_end_nodes() -> Result<()> {
+        let (instructions, network) = crate::parse(include_str!("./example-ghosts.txt"))?;
+        assert_eq!(calculate_ghost_steps(&network, &instructions)?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn cycles_combine_by_lcm_or_crt() -> Result<()> {
+        // ends at the end of each loop, lining up at the lowest common multiple
+        let cycles = [cycle(0, 4, &[], &[4]), cycle(0, 6, &[], &[6])];
+        assert_eq!(find_first_shared_end(&cycles)?, Some(12));
+
+        // 3 mod 5 & 4 mod 7
+        let cycles = [cycle(0, 5, &[], &[3]), cycle(2, 7, &[], &[4])];
+        assert_eq!(find_first_shared_end(&cycles)?, Some(18));
+
+        // moduli sharing a factor, with ends too early to count on the first pass
+        let cycles = [cycle(10, 6, &[], &[14]), cycle(0, 4, &[], &[2])];
+        assert_eq!(find_first_shared_end(&cycles)?, Some(14));
+        let cycles = [cycle(0, 6, &[], &[1]), cycle(0, 4, &[], &[2])];
+        assert_eq!(find_first_shared_end(&cycles)?, None);
+
+        // a lead in end beating any end within the loops
+        let cycles = [cycle(5, 10, &[2], &[7]), cycle(0, 2, &[], &[0])];
+        assert_eq!(find_first_shared_end(&cycles)?, Some(2));
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use common::error::{Error, Result};
+
+use crate::node::{Instructions, Network, NodeId};
+use crate::walk::WalkEnd;
+
+// where one whole pass of the instructions leads from each node, so a walk can be advanced a
+// pass at a time rather than a step at a time
+#[derive(Debug)]
+pub struct JumpTable<'a> {
+    network: &'a Network,
+    instructions: &'a Instructions,
+    // the node each pass ends on by node index, or none when the pass steps off the network
+    pass_ends: Vec<Option<usize>>,
+    // how many steps into each pass a target is first reached, if it is at all
+    first_targets: Vec<Option<usize>>,
+}
+
+impl<'a> JumpTable<'a> {
+    pub fn new(
+        network: &'a Network,
+        instructions: &'a Instructions,
+        is_target: impl Fn(&NodeId) -> bool,
+    ) -> Self {
+        let node_count = network.ids().len();
+        let mut pass_ends: Vec<Option<usize>> = Vec::with_capacity(node_count);
+        let mut first_targets: Vec<Option<usize>> = Vec::with_capacity(node_count);
+
+        for start in 0..node_count {
+            let mut index = Some(start);
+            let mut first_target = None;
+            for (offset, instruction) in instructions.iter().enumerate() {
+                let Some(current) = index else {
+                    break;
+                };
+                if first_target.is_none() && is_target(&network.ids()[current]) {
+                    first_target = Some(offset);
+                }
+                index = network.get_next_index(current, instruction).ok();
+            }
+            pass_ends.push(index);
+            first_targets.push(first_target);
+        }
+
+        Self {
+            network,
+            instructions,
+            pass_ends,
+            first_targets,
+        }
+    }
+
+    pub fn pass_end(&self, index: usize) -> Option<usize> {
+        self.pass_ends.get(index).copied().flatten()
+    }
+
+    pub fn steps_to_target(&self, start: &NodeId) -> Result<u64> {
+        let mut walk = self
+            .network
+            .walk(self.instructions, self.network.defined_index_of(start)?);
+
+        loop {
+            if let Some(offset) = self.first_targets[walk.index()] {
+                return Ok(walk.steps() + offset as u64);
+            }
+
+            match self.pass_ends[walk.index()] {
+                Some(pass_end) => {
+                    if !walk.skip_pass(pass_end)? {
+                        break;
+                    }
+                }
+                None => {
+                    // walks the pass a step at a time to find where it leaves the network
+                    walk.by_ref().for_each(drop);
+                    break;
+                }
+            }
+        }
+
+        Err(match walk.end() {
+            Some(WalkEnd::Dangling(error)) => error.clone(),
+            _ => Error::invalid(&format!(
+                "No target can be reached from {} by following the instructions",
+                start.as_str()
+            )),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn jump_table_skips_whole_passes() -> Result<()> {
+        let (instructions, network) = crate::parse(include_str!("./example.txt"))?;
+        let jump_table =
+            JumpTable::new(&network, &instructions, |node_id| node_id.as_str() == "ZZZ");
+        assert_eq!(
+            jump_table.pass_end(network.defined_index_of(&NodeId::new("AAA"))?),
+            network.index_of(&NodeId::new("BBB"))
+        );
+        assert_eq!(jump_table.steps_to_target(&NodeId::new("AAA"))?, 6);
+        assert_eq!(
+            network.calculate_steps_to_zzz(&instructions)?,
+            jump_table.steps_to_target(&NodeId::new("AAA"))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn jump_table_reports_unreachable_targets() -> Result<()> {
+        let instructions = Instructions::new("LL")?;
+        let network = Network::from_str("AAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)")?;
+        let jump_table =
+            JumpTable::new(&network, &instructions, |node_id| node_id.as_str() == "ZZZ");
+        let error = jump_table.steps_to_target(&NodeId::new("AAA")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "No target can be reached from AAA by following the instructions"
+        );
+
+        let network = Network::from_str("AAA = (BBB, ZZZ)\nBBB = (XXX, ZZZ)")?;
+        let jump_table =
+            JumpTable::new(&network, &instructions, |node_id| node_id.as_str() == "ZZZ");
+        let error = jump_table.steps_to_target(&NodeId::new("AAA")).unwrap_err();
+        assert_eq!(error.to_string(), "Node XXX is not in the network");
+        Ok(())
+    }
+}
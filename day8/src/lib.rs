@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use common::error::Result;
+use common::Solution;
+
+pub mod export;
+pub mod ghost;
+pub mod jump_table;
+pub mod node;
+pub mod walk;
+
+fn parse(input: &str) -> Result<(node::Instructions, node::Network)> {
+    let (instructions_line, network) = input.split_once('\n').unwrap_or((input, ""));
+    let instructions =
+        node::Instructions::new(instructions_line.trim()).map_err(|err| err.at_line(1))?;
+    let network = node::Network::from_str(network).map_err(|err| err.offset_line(1))?;
+
+    Ok((instructions, network))
+}
+
+fn process(input: &str) -> Result<u64> {
+    let (instructions, network) = parse(input)?;
+    network.calculate_steps_to_zzz(&instructions)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        process(input).map(|result| result.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let (instructions, network) = parse(input)?;
+        ghost::calculate_ghost_steps(&network, &instructions).map(|result| result.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn process_succeeds() {
+        let input = include_str!("./example.txt");
+        let result = process(input);
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn part2_passes() -> Result<()> {
+        let input = include_str!("./example-ghosts.txt");
+        assert_eq!(Day8.part2(input)?, "6");
+        Ok(())
+    }
+
+    #[test]
+    fn process_reports_line_of_malformed_node() {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA\n";
+        let error = process(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected node formatted as AAA = (BBB, CCC) at line 4"
+        );
+    }
+
+    #[test]
+    fn process_accepts_crlf_input() {
+        let input = include_str!("./example.txt").replace('\n', "\r\n");
+        assert_eq!(process(&input), Ok(6));
+    }
+}
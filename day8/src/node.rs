@@ -1,50 +1,66 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
 
-pub struct InstructionParseError {
-    reason: String,
-}
+use common::error::{Error, Result};
+
+use crate::walk::WalkEnd;
+
+const NODE_FORMAT: &str = "node formatted as AAA = (BBB, CCC)";
+const START_NODE_ID: &str = "AAA";
+const TARGET_NODE_ID: &str = "ZZZ";
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Instruction {
     Left,
     Right,
 }
 
 impl Instruction {
-    fn new(character: char) -> Result<Self, InstructionParseError> {
+    fn new(character: char) -> Result<Self> {
         match character {
             'L' => Ok(Instruction::Left),
             'R' => Ok(Instruction::Right),
-            _ => Err(InstructionParseError {
-                reason: format!("Failed to parse {} into instruction", character),
-            }),
+            _ => Err(Error::invalid_character(character)),
         }
     }
 }
 
-#[derive(Debug)]
-pub struct InstructionsParseError {
-    reason: String,
-}
-
 #[derive(Debug)]
 pub struct Instructions(Vec<Instruction>);
 
 impl Instructions {
-    pub fn new(line_of_instructions: &str) -> Result<Self, InstructionsParseError> {
+    pub fn new(line_of_instructions: &str) -> Result<Self> {
         let mut instructions: Vec<Instruction> = vec![];
 
-        for instruction_char in line_of_instructions.chars() {
-            let instruction = match Instruction::new(instruction_char) {
-                Ok(instruction) => instruction,
-                Err(err) => return Err(InstructionsParseError { reason: err.reason }),
-            };
+        for (index, instruction_char) in line_of_instructions.chars().enumerate() {
+            let instruction =
+                Instruction::new(instruction_char).map_err(|err| err.at_column(index + 1))?;
 
             instructions.push(instruction);
         }
 
+        if instructions.is_empty() {
+            return Err(Error::missing("at least one instruction"));
+        }
+
         Ok(Self(instructions))
     }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Instruction> {
+        self.0.get(index).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Instruction> + '_ {
+        self.0.iter().copied()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -54,6 +70,26 @@ impl NodeId {
     pub fn new(id: &str) -> Self {
         Self(id.to_string())
     }
+
+    // ids may be any length, but only of letters & digits
+    fn parse(id: &str) -> Result<Self> {
+        if id.is_empty() {
+            return Err(Error::missing(NODE_FORMAT));
+        }
+        match id
+            .char_indices()
+            .find(|(_, character)| !character.is_alphanumeric())
+        {
+            Some((index, character)) => {
+                Err(Error::invalid_character(character).at_column(index + 1))
+            }
+            None => Ok(Self::new(id)),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 #[derive(Debug)]
@@ -73,46 +109,270 @@ impl Node {
     }
 }
 
-#[derive(Debug)]
+// a node written as `AAA = (BBB, CCC)`, with any amount of space around each part
+impl FromStr for Node {
+    type Err = Error;
+    fn from_str(string: &str) -> Result<Self> {
+        let (id, neighbours) = string.split_once('=').ok_or(Error::missing(NODE_FORMAT))?;
+        let (left_node_id, right_node_id) = neighbours
+            .trim()
+            .strip_prefix('(')
+            .and_then(|neighbours| neighbours.strip_suffix(')'))
+            .and_then(|neighbours| neighbours.split_once(','))
+            .ok_or(Error::missing(NODE_FORMAT))?;
+
+        let parse_id = |id: &str| {
+            let id = id.trim();
+            NodeId::parse(id).map_err(|error| {
+                let column = common::error::column_of(string, id).unwrap_or(1);
+                error.offset_column(column - 1)
+            })
+        };
+
+        Ok(Self {
+            id: parse_id(id)?,
+            left_node_id: parse_id(left_node_id)?,
+            right_node_id: parse_id(right_node_id)?,
+        })
+    }
+}
+
+// a node pointing at a neighbour which isn't in the network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingReference {
+    pub node_id: NodeId,
+    pub instruction: Instruction,
+    pub missing_node_id: NodeId,
+}
+
+// each list sorted by node id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Validation {
+    pub dangling_references: Vec<DanglingReference>,
+    // nodes no path from the start leads to, whichever way the instructions go
+    pub unreachable_nodes: Vec<NodeId>,
+    // targets the instructions do & don't lead to from the start
+    pub reachable_targets: Vec<NodeId>,
+    pub unreachable_targets: Vec<NodeId>,
+}
+
+impl Validation {
+    // the walk can't step off the network & reaches at least one target
+    pub fn is_valid(&self) -> bool {
+        self.dangling_references.is_empty() && !self.reachable_targets.is_empty()
+    }
+}
+
+// node ids are interned into dense indices, with each node's neighbours stored by index
+#[derive(Debug, Default)]
 pub struct Network {
-    nodes: HashMap<NodeId, Node>,
+    // every id seen, including neighbours which are never defined
+    ids: Vec<NodeId>,
+    indices: HashMap<NodeId, usize>,
+    defined: Vec<bool>,
+    lefts: Vec<usize>,
+    rights: Vec<usize>,
 }
 
 impl Network {
     pub fn new() -> Self {
-        Self {
-            nodes: HashMap::new(),
+        Self::default()
+    }
+
+    fn intern(&mut self, id: NodeId) -> usize {
+        if let Some(index) = self.indices.get(&id) {
+            return *index;
+        }
+        let index = self.ids.len();
+        self.indices.insert(id.clone(), index);
+        self.ids.push(id);
+        self.defined.push(false);
+        // undefined nodes lead nowhere, so point back at themselves until they're defined
+        self.lefts.push(index);
+        self.rights.push(index);
+        index
+    }
+
+    pub fn add_node(&mut self, node: Node) -> Result<()> {
+        let index = self.intern(node.id);
+        if self.defined[index] {
+            return Err(Error::invalid(&format!(
+                "Node {} is defined more than once",
+                self.ids[index].0
+            )));
+        }
+        self.defined[index] = true;
+        self.lefts[index] = self.intern(node.left_node_id);
+        self.rights[index] = self.intern(node.right_node_id);
+        Ok(())
+    }
+
+    pub fn node_ids(&self) -> impl Iterator<Item = &NodeId> {
+        self.ids
+            .iter()
+            .zip(self.defined.iter())
+            .filter(|(_, defined)| **defined)
+            .map(|(id, _)| id)
+    }
+
+    // every interned id by its index, including neighbours which are never defined
+    pub fn ids(&self) -> &[NodeId] {
+        &self.ids
+    }
+
+    pub fn index_of(&self, node_id: &NodeId) -> Option<usize> {
+        self.indices.get(node_id).copied()
+    }
+
+    pub fn is_defined(&self, index: usize) -> bool {
+        self.defined.get(index).copied().unwrap_or(false)
+    }
+
+    fn not_in_network_error(node_id: &NodeId) -> Error {
+        Error::invalid(&format!("Node {} is not in the network", node_id.0))
+    }
+
+    // the index of a defined node, rather than one only ever referenced as a neighbour
+    pub fn defined_index_of(&self, node_id: &NodeId) -> Result<usize> {
+        self.index_of(node_id)
+            .filter(|index| self.defined[*index])
+            .ok_or_else(|| Self::not_in_network_error(node_id))
+    }
+
+    // indices are only handed out by the network, but may be made up by callers
+    pub fn check_index(&self, index: usize) -> Result<()> {
+        if index >= self.ids.len() {
+            return Err(Error::invalid(&format!("No node has index {}", index)));
         }
+        Ok(())
     }
 
-    pub fn add_node(&mut self, node: Node) {
-        self.nodes.insert(node.id.clone(), node);
+    pub fn get_next_index(&self, index: usize, instruction: Instruction) -> Result<usize> {
+        self.check_index(index)?;
+        if !self.defined[index] {
+            return Err(Self::not_in_network_error(&self.ids[index]));
+        }
+        Ok(match instruction {
+            Instruction::Left => self.lefts[index],
+            Instruction::Right => self.rights[index],
+        })
     }
 
-    pub fn calculate_steps_to_zzz(&self, instructions: &Instructions) -> Result<u32, String> {
-        let mut steps: u32 = 0;
-        let mut node_id = NodeId::new("AAA");
-        let mut instruction_index = 0;
+    pub fn get_next_node_id(&self, node_id: &NodeId, instruction: Instruction) -> Result<&NodeId> {
+        let index = self.defined_index_of(node_id)?;
+        Ok(&self.ids[self.get_next_index(index, instruction)?])
+    }
 
-        while node_id.0 != "ZZZ" {
-            let node = self.nodes.get(&node_id).expect("Failed");
+    pub fn calculate_steps_to_zzz(&self, instructions: &Instructions) -> Result<u64> {
+        let start = self.defined_index_of(&NodeId::new(START_NODE_ID))?;
+        let target = self.index_of(&NodeId::new(TARGET_NODE_ID));
+        let mut walk = self.walk(instructions, start);
 
-            if instruction_index == instructions.0.len() {
-                instruction_index = 0;
+        while Some(walk.index()) != target {
+            if walk.next().is_none() {
+                return Err(match walk.end() {
+                    Some(WalkEnd::Dangling(error)) => error.clone(),
+                    _ => Error::invalid(&format!(
+                        "{} can't be reached from {} by following the instructions",
+                        TARGET_NODE_ID, START_NODE_ID
+                    )),
+                });
             }
+        }
+
+        Ok(walk.steps())
+    }
 
-            let instruction = instructions.0[instruction_index];
+    pub fn validate(
+        &self,
+        instructions: &Instructions,
+        start: &NodeId,
+        is_target: impl Fn(&NodeId) -> bool,
+    ) -> Result<Validation> {
+        let start = self.defined_index_of(start)?;
 
-            match instruction {
-                Instruction::Left => node_id = node.left_node_id.clone(),
-                Instruction::Right => node_id = node.right_node_id.clone(),
-            };
+        let mut indices: Vec<usize> = (0..self.ids.len())
+            .filter(|index| self.defined[*index])
+            .collect();
+        indices.sort_by(|a, b| self.ids[*a].0.cmp(&self.ids[*b].0));
+
+        let dangling_references = indices
+            .iter()
+            .flat_map(|index| {
+                [
+                    (Instruction::Left, self.lefts[*index]),
+                    (Instruction::Right, self.rights[*index]),
+                ]
+                .into_iter()
+                .filter(|(_, neighbour)| !self.defined[*neighbour])
+                .map(|(instruction, neighbour)| DanglingReference {
+                    node_id: self.ids[*index].clone(),
+                    instruction,
+                    missing_node_id: self.ids[neighbour].clone(),
+                })
+            })
+            .collect();
+
+        // every node some path leads to, whichever way the instructions go
+        let mut reachable: Vec<bool> = vec![false; self.ids.len()];
+        reachable[start] = true;
+        let mut queue: VecDeque<usize> = VecDeque::from([start]);
+        while let Some(index) = queue.pop_front() {
+            for neighbour in [self.lefts[index], self.rights[index]] {
+                if self.defined[neighbour] && !reachable[neighbour] {
+                    reachable[neighbour] = true;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
 
-            instruction_index += 1;
-            steps += 1;
+        // the nodes actually walked by following the instructions until going round in circles
+        let mut walked: Vec<bool> = vec![false; self.ids.len()];
+        walked[start] = true;
+        for step in self.walk(instructions, start) {
+            walked[step.to] = true;
         }
 
-        Ok(steps)
+        let unreachable_nodes = indices
+            .iter()
+            .filter(|index| !reachable[**index])
+            .map(|index| self.ids[*index].clone())
+            .collect();
+        let (reachable_targets, unreachable_targets): (Vec<usize>, Vec<usize>) = indices
+            .iter()
+            .copied()
+            .filter(|index| is_target(&self.ids[*index]))
+            .partition(|index| walked[*index]);
+
+        Ok(Validation {
+            dangling_references,
+            unreachable_nodes,
+            reachable_targets: reachable_targets
+                .into_iter()
+                .map(|index| self.ids[index].clone())
+                .collect(),
+            unreachable_targets: unreachable_targets
+                .into_iter()
+                .map(|index| self.ids[index].clone())
+                .collect(),
+        })
+    }
+}
+
+// one node per line, where blank lines are skipped
+impl FromStr for Network {
+    type Err = Error;
+    fn from_str(string: &str) -> Result<Self> {
+        let mut network = Network::new();
+        for (line_index, line) in string.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            Node::from_str(line)
+                .and_then(|node| network.add_node(node))
+                .map_err(|error| error.at_line(line_index + 1))?;
+        }
+        Ok(network)
     }
 }
 
@@ -130,4 +390,98 @@ mod test {
         assert_eq!(node.left_node_id.0, left_node_id);
         assert_eq!(node.right_node_id.0, right_node_id);
     }
+
+    #[test]
+    fn nodes_parse_ids_of_any_length_and_spacing() -> Result<()> {
+        let node = Node::from_str("  A1 =(LEFT ,  R )\t")?;
+        assert_eq!(node.id.0, "A1");
+        assert_eq!(node.left_node_id.0, "LEFT");
+        assert_eq!(node.right_node_id.0, "R");
+
+        let error = Node::from_str("AAA = (B-B, CCC)").unwrap_err();
+        assert_eq!(error.to_string(), "Unexpected character '-' at column 9");
+
+        let error = Node::from_str("AAA = (, CCC)").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected node formatted as AAA = (BBB, CCC)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn networks_parse_crlf_and_reject_duplicate_nodes() -> Result<()> {
+        let network = Network::from_str("AAA = (BBB, BBB)\r\n\r\nBBB = (AAA, ZZZ)\r\n")?;
+        assert_eq!(network.node_ids().count(), 2);
+        assert_eq!(
+            network.get_next_node_id(&NodeId::new("BBB"), Instruction::Right)?,
+            &NodeId::new("ZZZ")
+        );
+
+        let error =
+            Network::from_str("AAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nAAA = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Node AAA is defined more than once at line 3"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validation_reports_dangling_and_unreachable_nodes() -> Result<()> {
+        let network = Network::from_str(
+            "AAA = (BBB, ZZZ)\nBBB = (AAA, XXX)\nZZZ = (ZZZ, ZZZ)\nCCC = (CCC, CCC)",
+        )?;
+        let is_target = |node_id: &NodeId| node_id.0 == "ZZZ";
+
+        let validation =
+            network.validate(&Instructions::new("L")?, &NodeId::new("AAA"), is_target)?;
+        assert_eq!(
+            validation,
+            Validation {
+                dangling_references: vec![DanglingReference {
+                    node_id: NodeId::new("BBB"),
+                    instruction: Instruction::Right,
+                    missing_node_id: NodeId::new("XXX"),
+                }],
+                unreachable_nodes: vec![NodeId::new("CCC")],
+                reachable_targets: vec![],
+                unreachable_targets: vec![NodeId::new("ZZZ")],
+            }
+        );
+        assert!(!validation.is_valid());
+
+        let validation =
+            network.validate(&Instructions::new("RL")?, &NodeId::new("AAA"), is_target)?;
+        assert_eq!(validation.reachable_targets, vec![NodeId::new("ZZZ")]);
+
+        let error = network
+            .validate(&Instructions::new("L")?, &NodeId::new("YYY"), is_target)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Node YYY is not in the network");
+        Ok(())
+    }
+
+    #[test]
+    fn walking_in_circles_is_an_error() -> Result<()> {
+        let network = Network::from_str("AAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)")?;
+        let error = network
+            .calculate_steps_to_zzz(&Instructions::new("LL")?)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "ZZZ can't be reached from AAA by following the instructions"
+        );
+        assert_eq!(
+            network.calculate_steps_to_zzz(&Instructions::new("LR")?)?,
+            2
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn instructions_report_column_of_invalid_instruction() {
+        let error = Instructions::new("LRLXR").unwrap_err();
+        assert_eq!(error.to_string(), "Unexpected character 'X' at column 4");
+    }
 }
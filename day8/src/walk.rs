@@ -0,0 +1,173 @@
+use common::error::{Error, Result};
+
+use crate::node::{Instruction, Instructions, Network};
+
+// a single step taken by following the instructions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalkStep {
+    // 1-based count of steps taken so far
+    pub step: u64,
+    pub from: usize,
+    pub instruction: Instruction,
+    pub to: usize,
+}
+
+// why a walk stopped short of its caller's goal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalkEnd {
+    // a pass was about to start on a node one already started on at `cycle_start`, so it would
+    // only go round in circles
+    Circling { cycle_start: u64 },
+    // the walk stepped onto a node which isn't in the network
+    Dangling(Error),
+}
+
+// follows the instructions a step at a time from a node, until going round in circles or
+// leaving the network
+#[derive(Debug)]
+pub struct Walk<'a> {
+    network: &'a Network,
+    instructions: &'a Instructions,
+    index: usize,
+    steps: u64,
+    // the step a pass of the instructions first started on each node, if one has
+    passes_started: Vec<Option<u64>>,
+    end: Option<WalkEnd>,
+}
+
+impl<'a> Walk<'a> {
+    // the node the walk is on
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn end(&self) -> Option<&WalkEnd> {
+        self.end.as_ref()
+    }
+
+    fn instruction_index(&self) -> usize {
+        (self.steps % self.instructions.len() as u64) as usize
+    }
+
+    // true, & ends the walk, when about to start a pass on a node one already started on
+    fn is_circling(&mut self) -> bool {
+        if self.end.is_none() && self.instruction_index() == 0 {
+            // an index outside the network is left for stepping to report
+            if let Some(pass_started) = self.passes_started.get_mut(self.index) {
+                if let Some(cycle_start) = pass_started.replace(self.steps) {
+                    self.end = Some(WalkEnd::Circling { cycle_start });
+                }
+            }
+        }
+        self.end.is_some()
+    }
+
+    // jumps straight to where the pass starting on the current node ends, for callers which
+    // already know, stopping as stepping would when the pass would go round in circles
+    pub fn skip_pass(&mut self, pass_end: usize) -> Result<bool> {
+        self.network.check_index(pass_end)?;
+        if self.is_circling() {
+            return Ok(false);
+        }
+        self.index = pass_end;
+        self.steps += self.instructions.len() as u64;
+        Ok(true)
+    }
+}
+
+impl Iterator for Walk<'_> {
+    type Item = WalkStep;
+
+    fn next(&mut self) -> Option<WalkStep> {
+        if self.is_circling() {
+            return None;
+        }
+        let instruction = self.instructions.get(self.instruction_index())?;
+        let to = match self.network.get_next_index(self.index, instruction) {
+            Ok(to) => to,
+            Err(error) => {
+                self.end = Some(WalkEnd::Dangling(error));
+                return None;
+            }
+        };
+
+        self.steps += 1;
+        let step = WalkStep {
+            step: self.steps,
+            from: self.index,
+            instruction,
+            to,
+        };
+        self.index = to;
+        Some(step)
+    }
+}
+
+impl Network {
+    pub fn walk<'a>(&'a self, instructions: &'a Instructions, start: usize) -> Walk<'a> {
+        Walk {
+            network: self,
+            instructions,
+            index: start,
+            steps: 0,
+            passes_started: vec![None; self.ids().len()],
+            end: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::node::NodeId;
+
+    #[test]
+    fn walks_stop_on_circling_or_dangling_nodes() -> Result<()> {
+        let network = Network::from_str("AAA = (BBB, XXX)\nBBB = (AAA, BBB)")?;
+        let start = network.defined_index_of(&NodeId::new("AAA"))?;
+
+        let instructions = Instructions::new("LL")?;
+        let mut walk = network.walk(&instructions, start);
+        let steps: Vec<u64> = walk.by_ref().map(|step| step.step).collect();
+        assert_eq!(steps, vec![1, 2]);
+        assert_eq!(walk.index(), start);
+        assert_eq!(walk.end(), Some(&WalkEnd::Circling { cycle_start: 0 }));
+        assert_eq!(
+            walk.skip_pass(3).unwrap_err().to_string(),
+            "No node has index 3"
+        );
+
+        let mut walk = network.walk(&instructions, 3);
+        assert_eq!(walk.next(), None);
+        assert_eq!(
+            walk.end(),
+            Some(&WalkEnd::Dangling(Error::invalid("No node has index 3")))
+        );
+
+        let instructions = Instructions::new("R")?;
+        let mut walk = network.walk(&instructions, start);
+        let steps: Vec<WalkStep> = walk.by_ref().collect();
+        assert_eq!(
+            steps,
+            vec![WalkStep {
+                step: 1,
+                from: start,
+                instruction: Instruction::Right,
+                to: network.index_of(&NodeId::new("XXX")).unwrap_or_default(),
+            }]
+        );
+        assert_eq!(
+            walk.end(),
+            Some(&WalkEnd::Dangling(Error::invalid(
+                "Node XXX is not in the network"
+            )))
+        );
+        Ok(())
+    }
+}
@@ -1,152 +1,53 @@
-use std::fs::read_to_string;
+use common::error::{Error, Result};
+use common::input::InputSource;
+use common::Solution;
 
-#[derive(Debug)]
-pub struct Report {
-    areas: Vec<Area>,
-}
+pub mod oasis;
 
-impl Report {
-    pub fn get_next_value_area_prediction_sum(&self) -> i32 {
-        self.areas
-            .iter()
-            .map(|area| area.get_next_value_prediction())
-            .sum()
-    }
+use oasis::Report;
 
-    pub fn get_past_value_area_prediction_sum(&self) -> i32 {
-        self.areas
-            .iter()
-            .map(|area| area.get_past_value_prediction())
-            .sum()
-    }
-}
+pub struct Day9;
 
-impl TryFrom<&str> for Report {
-    type Error = String;
-    fn try_from(input: &str) -> Result<Self, Self::Error> {
-        match input.lines().map(|line| Area::try_from(line)).collect() {
-            Ok(areas) => Ok(Self { areas }),
-            Err(error) => Err(error),
-        }
+impl Solution for Day9 {
+    fn day(&self) -> u8 {
+        9
     }
-}
 
-#[derive(Debug)]
-struct Area {
-    history: Vec<Value>,
-}
-
-impl Area {
-    fn get_next_value_prediction(&self) -> i32 {
-        let latest_value = self.history.last().unwrap().0;
-        let mut last_nums: Vec<i32> = vec![latest_value];
-        let mut diffs: Vec<i32> = self
-            .history
-            .windows(2)
-            .map(|pair| pair[1].0 - pair[0].0)
-            .collect();
-        last_nums.push(*diffs.last().unwrap());
-
-        while !diffs.iter().all(|diff| *diff == 0) {
-            diffs = diffs.windows(2).map(|pair| pair[1] - pair[0]).collect();
-            last_nums.push(*diffs.last().unwrap())
-        }
-
-        last_nums.iter().sum()
-    }
-
-    fn get_history_reversed(&self) -> Vec<Value> {
-        let mut history_reversed = self.history.clone();
-        history_reversed.reverse();
-        history_reversed
+    fn part1(&self, input: &str) -> Result<String> {
+        let report: Report = Report::try_from(input)?;
+        Ok(report.get_next_value_area_prediction_sum()?.to_string())
     }
 
-    fn get_past_value_prediction(&self) -> i32 {
-        let reversed_history = self.get_history_reversed();
-        let latest_value = reversed_history.last().unwrap().0;
-        let mut last_nums: Vec<i32> = vec![latest_value];
-        let mut diffs: Vec<i32> = reversed_history
-            .windows(2)
-            .map(|pair| pair[1].0 - pair[0].0)
-            .collect();
-        last_nums.push(*diffs.last().unwrap());
-
-        while !diffs.iter().all(|diff| *diff == 0) {
-            diffs = diffs.windows(2).map(|pair| pair[1] - pair[0]).collect();
-            last_nums.push(*diffs.last().unwrap())
-        }
-
-        last_nums.iter().sum()
-    }
-}
-
-impl TryFrom<&str> for Area {
-    type Error = String;
-    fn try_from(line: &str) -> Result<Self, Self::Error> {
-        match line
-            .split_whitespace()
-            .map(|value| Value::try_from(value))
-            .collect()
-        {
-            Ok(history) => Ok(Self { history }),
-            Err(error) => Err(error),
-        }
-    }
-}
-
-#[derive(Debug, Clone)]
-struct Value(i32);
-
-impl TryFrom<&str> for Value {
-    type Error = String;
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value.parse::<i32>() {
-            Ok(parsed_value) => Ok(Self(parsed_value)),
-            Err(error) => Err(error.to_string()),
-        }
+    fn part2(&self, input: &str) -> Result<String> {
+        let report: Report = Report::try_from(input)?;
+        Ok(report.get_past_value_area_prediction_sum()?.to_string())
     }
 }
 
 #[allow(dead_code)]
-fn highest_number_in_file(path: &str) -> Result<i32, String> {
-    let file_contents = match read_to_string(path) {
-        Ok(contents) => contents,
-        Err(_) => return Err(format!("Couldn't find file: {}", path)),
-    };
-
-    let max = file_contents
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|num_str| num_str.parse::<i32>().unwrap())
-                .max()
-                .unwrap()
-        })
+fn highest_number_in_input(source: &InputSource) -> Result<i64> {
+    let file_contents = source.read(9)?;
+    let report: Report = Report::try_from(file_contents.as_str())?;
+
+    report
+        .areas()
+        .iter()
+        .flat_map(|area| area.history().iter().map(|value| *value.get()))
         .max()
-        .unwrap();
-
-    Ok(max)
+        .ok_or(Error::missing("at least one value"))
 }
 
 #[allow(dead_code)]
-fn lowest_number_in_file(path: &str) -> Result<i32, String> {
-    let file_contents = match read_to_string(path) {
-        Ok(contents) => contents,
-        Err(_) => return Err(format!("Couldn't find file: {}", path)),
-    };
-
-    let min = file_contents
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|num| num.parse::<i32>().unwrap())
-                .min()
-                .unwrap()
-        })
+fn lowest_number_in_input(source: &InputSource) -> Result<i64> {
+    let file_contents = source.read(9)?;
+    let report: Report = Report::try_from(file_contents.as_str())?;
+
+    report
+        .areas()
+        .iter()
+        .flat_map(|area| area.history().iter().map(|value| *value.get()))
         .min()
-        .unwrap();
-
-    Ok(min)
+        .ok_or(Error::missing("at least one value"))
 }
 
 #[cfg(test)]
@@ -154,20 +55,30 @@ mod test {
     use super::*;
 
     #[test]
-    fn gets_highest_number_in_file() {
-        let result = highest_number_in_file("./example.txt");
+    fn report_parse_reports_location_of_invalid_value() {
+        let input = "0 3 6 9 12 15\n1 3 six 10 15 21";
+        let error = Report::<i64>::try_from(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"six\" into a number at line 2, column 5"
+        );
+    }
+
+    #[test]
+    fn gets_highest_number_in_input() {
+        let result = highest_number_in_input(&InputSource::from("./example.txt"));
         assert_eq!(result, Ok(45));
 
-        let result = highest_number_in_file("./input.txt");
+        let result = highest_number_in_input(&InputSource::Cache);
         assert_eq!(result, Ok(21793992));
     }
 
     #[test]
-    fn gets_lowest_number_in_file() {
-        let result = lowest_number_in_file("./example.txt");
+    fn gets_lowest_number_in_input() {
+        let result = lowest_number_in_input(&InputSource::from("./example.txt"));
         assert_eq!(result, Ok(0));
 
-        let result = lowest_number_in_file("./input.txt");
+        let result = lowest_number_in_input(&InputSource::Cache);
         assert_eq!(result, Ok(-1698921));
     }
 }
@@ -0,0 +1,259 @@
+use common::error::{Error, Result};
+
+use crate::oasis::polynomial::PolynomialFit;
+use crate::oasis::value::{overflow_error, Number, Value};
+
+#[derive(Debug)]
+pub struct Area<T = i64> {
+    history: Vec<Value<T>>,
+    // the first value of each row of the difference table, stopping before a row of zeros
+    leading_differences: Vec<T>,
+    // whether that row of zeros was reached before the rows ran out
+    is_polynomial: bool,
+}
+
+impl<T: Number> Area<T> {
+    pub fn new(history: Vec<Value<T>>) -> Result<Self> {
+        if history.is_empty() {
+            return Err(Error::missing("at least one value"));
+        }
+
+        let mut leading_differences: Vec<T> = vec![];
+        let mut differences: Vec<T> = history.iter().map(|value| value.get().clone()).collect();
+        while differences.iter().any(|difference| !difference.is_zero()) {
+            leading_differences.push(differences[0].clone());
+            differences = differences
+                .windows(2)
+                .map(|pair| {
+                    pair[1]
+                        .checked_sub(&pair[0])
+                        .ok_or_else(overflow_error::<T>)
+                })
+                .collect::<Result<_>>()?;
+        }
+
+        Ok(Self {
+            history,
+            leading_differences,
+            is_polynomial: !differences.is_empty(),
+        })
+    }
+
+    pub fn history(&self) -> &[Value<T>] {
+        &self.history
+    }
+
+    // the depth of the difference table before its rows become all zeros
+    pub fn degree(&self) -> usize {
+        self.leading_differences.len().saturating_sub(1)
+    }
+
+    // a history never reaching a row of zeros is only fitted by the highest degree polynomial
+    // it has values for, so its predictions can't be trusted
+    pub fn is_polynomial(&self) -> bool {
+        self.is_polynomial
+    }
+
+    pub fn fit(&self) -> Result<PolynomialFit> {
+        PolynomialFit::from_leading_differences(&self.leading_differences, self.is_polynomial)
+    }
+
+    // the value at a position, 0 being the earliest recorded value, using newton's forward
+    // difference formula: the sum of each leading difference times (position choose its row)
+    pub fn value_at(&self, position: i64) -> Result<T> {
+        let number = |value: i64| T::from_i64(value).ok_or_else(overflow_error::<T>);
+        let mut binomial = number(1)?;
+        let mut value = T::zero();
+        for (row, difference) in self.leading_differences.iter().enumerate() {
+            if row > 0 {
+                let row = row as i64;
+                let factor = position
+                    .checked_sub(row - 1)
+                    .ok_or_else(overflow_error::<T>)?;
+                binomial = binomial
+                    .checked_mul(&number(factor)?)
+                    .and_then(|product| product.checked_div(&T::from_i64(row)?))
+                    .ok_or_else(overflow_error::<T>)?;
+            }
+            value = binomial
+                .checked_mul(difference)
+                .and_then(|term| value.checked_add(&term))
+                .ok_or_else(overflow_error::<T>)?;
+        }
+        Ok(value)
+    }
+
+    // the value a number of steps after the latest recorded value, or before the earliest
+    // recorded value when negative
+    pub fn predict(&self, horizon: i64) -> Result<T> {
+        if horizon < 0 {
+            self.value_at(horizon)
+        } else {
+            let position = (self.history.len() as i64 - 1)
+                .checked_add(horizon)
+                .ok_or_else(overflow_error::<T>)?;
+            self.value_at(position)
+        }
+    }
+}
+
+impl<T: Number> TryFrom<&str> for Area<T> {
+    type Error = Error;
+    fn try_from(line: &str) -> Result<Self> {
+        let history = line
+            .split_whitespace()
+            .map(|value| Value::try_from(value).map_err(|error| error.at_column_of(line, value)))
+            .collect::<Result<Vec<Value<T>>>>()?;
+        Self::new(history)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::oasis::rational::Rational;
+
+    // extends the difference table a step at a time, as the puzzle describes
+    fn extend(values: &[i128]) -> i128 {
+        if values.iter().all(|value| *value == 0) {
+            return 0;
+        }
+        let differences: Vec<i128> = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        values[values.len() - 1] + extend(&differences)
+    }
+
+    #[test]
+    fn areas_reproduce_their_history() -> Result<()> {
+        let area: Area<i128> = Area::try_from("10 13 16 21 30 45")?;
+        let values: Vec<i128> = (0..6)
+            .map(|position| area.value_at(position))
+            .collect::<Result<_>>()?;
+        assert_eq!(values, vec![10, 13, 16, 21, 30, 45]);
+        Ok(())
+    }
+
+    #[test]
+    fn areas_predict_any_horizon() -> Result<()> {
+        let area: Area<i128> = Area::try_from("10 13 16 21 30 45")?;
+        assert_eq!(area.predict(1)?, 68);
+        assert_eq!(area.predict(-1)?, 5);
+        assert_eq!(area.predict(0)?, 45);
+
+        let mut values: Vec<i128> = vec![10, 13, 16, 21, 30, 45];
+        for horizon in 1..=20 {
+            let next_value = extend(&values);
+            assert_eq!(area.predict(horizon)?, next_value);
+            values.push(next_value);
+        }
+
+        let mut values: Vec<i128> = vec![45, 30, 21, 16, 13, 10];
+        for horizon in 1..=20 {
+            let past_value = extend(&values);
+            assert_eq!(area.predict(-horizon)?, past_value);
+            values.push(past_value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn constant_areas_stay_constant() -> Result<()> {
+        assert_eq!(Area::<i64>::try_from("0 0 0")?.predict(5)?, 0);
+        assert_eq!(Area::<i64>::try_from("7")?.predict(-3)?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn areas_report_their_generating_polynomial() -> Result<()> {
+        let area = Area::<i64>::try_from("0 3 6 9 12 15")?;
+        assert_eq!(area.degree(), 1);
+        assert_eq!(area.fit()?.to_string(), "3x");
+
+        let fit = Area::<i64>::try_from("1 3 6 10 15 21")?.fit()?;
+        assert_eq!(
+            fit,
+            PolynomialFit {
+                degree: 2,
+                coefficients: vec![
+                    Rational::integer(1),
+                    Rational::new(3, 2).unwrap(),
+                    Rational::new(1, 2).unwrap()
+                ],
+                is_polynomial: true,
+            }
+        );
+        assert!(!fit.has_integer_coefficients());
+        assert_eq!(fit.to_string(), "1/2x^2 + 3/2x + 1");
+
+        let fit = Area::<i64>::try_from("10 13 16 21 30 45")?.fit()?;
+        assert_eq!(fit.degree, 3);
+        assert_eq!(fit.to_string(), "1/3x^3 - x^2 + 11/3x + 10");
+
+        let fit = Area::<i64>::try_from("5 2 -1 -4")?.fit()?;
+        assert!(fit.has_integer_coefficients());
+        assert_eq!(fit.to_string(), "-3x + 5");
+
+        let fit = Area::<i128>::try_from("-170141183460469231731687303715884105728")?.fit()?;
+        assert_eq!(fit.to_string(), "-170141183460469231731687303715884105728");
+        Ok(())
+    }
+
+    #[test]
+    fn short_and_non_polynomial_areas_are_flagged() -> Result<()> {
+        let area = Area::<i64>::try_from("1 2 4 8 16")?;
+        assert!(!area.is_polynomial());
+        assert_eq!(area.degree(), 4);
+        assert_eq!(area.predict(1)?, 31);
+
+        let area = Area::<i64>::try_from("7")?;
+        assert!(!area.is_polynomial());
+        assert_eq!(area.fit()?.to_string(), "7");
+
+        let area = Area::<i64>::try_from("0 0")?;
+        assert!(area.is_polynomial());
+        assert_eq!(area.degree(), 0);
+        assert_eq!(area.fit()?.to_string(), "0");
+        Ok(())
+    }
+
+    #[test]
+    fn overflow_is_reported_rather_than_wrapped() -> Result<()> {
+        let error = Area::<i32>::try_from("2147483647 -2147483648").unwrap_err();
+        assert_eq!(error.to_string(), "Prediction overflows i32");
+
+        // the same history, predicted far enough ahead, only fits within wider types
+        let history = "1 8 27 64 125";
+        let error = Area::<i32>::try_from(history)?.predict(2000).unwrap_err();
+        assert_eq!(error.to_string(), "Prediction overflows i32");
+        assert_eq!(
+            Area::<i64>::try_from(history)?.predict(2000)?,
+            2005_i64.pow(3)
+        );
+        assert_eq!(
+            Area::<Rational>::try_from(history)?.predict(2000)?,
+            Rational::integer(2005_i128.pow(3))
+        );
+
+        // horizons so far out that the position itself overflows
+        let area = Area::<i64>::try_from("1 4 9")?;
+        let error = area.predict(i64::MAX).unwrap_err();
+        assert_eq!(error.to_string(), "Prediction overflows i64");
+        let error = area.predict(i64::MIN).unwrap_err();
+        assert_eq!(error.to_string(), "Prediction overflows i64");
+
+        let error = Area::<i32>::try_from("a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"a\" into a number at column 1"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rational_areas_predict_exactly() -> Result<()> {
+        // half of the triangular numbers
+        let area: Area<Rational> = Area::try_from("0 1/2 3/2 3 5")?;
+        assert_eq!(area.predict(1)?, Rational::new(15, 2).unwrap());
+        assert_eq!(area.predict(-1)?, Rational::integer(0));
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use common::error::{Error, Result};
+
+pub mod area;
+pub mod polynomial;
+pub mod rational;
+pub mod stream;
+pub mod value;
+
+use area::Area;
+use value::{overflow_error, Number};
+
+#[derive(Debug)]
+pub struct Report<T = i64> {
+    areas: Vec<Area<T>>,
+}
+
+impl<T: Number> Report<T> {
+    pub fn areas(&self) -> &[Area<T>] {
+        &self.areas
+    }
+
+    // the sum of every area's prediction a number of steps after its latest value, or before
+    // its earliest value when negative
+    pub fn get_prediction_sum(&self, horizon: i64) -> Result<T> {
+        self.areas.iter().try_fold(T::zero(), |sum, area| {
+            sum.checked_add(&area.predict(horizon)?)
+                .ok_or_else(overflow_error::<T>)
+        })
+    }
+
+    pub fn get_next_value_area_prediction_sum(&self) -> Result<T> {
+        self.get_prediction_sum(1)
+    }
+
+    pub fn get_past_value_area_prediction_sum(&self) -> Result<T> {
+        self.get_prediction_sum(-1)
+    }
+}
+
+impl<T: Number> TryFrom<&str> for Report<T> {
+    type Error = Error;
+    fn try_from(input: &str) -> Result<Self> {
+        match input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                Area::try_from(line).map_err(|error| error.at_line(line_index + 1))
+            })
+            .collect()
+        {
+            Ok(areas) => Ok(Self { areas }),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reports_sum_predictions_for_any_horizon() -> Result<()> {
+        let report: Report = Report::try_from(include_str!("../../example.txt"))?;
+        assert_eq!(report.get_next_value_area_prediction_sum()?, 114);
+        assert_eq!(report.get_past_value_area_prediction_sum()?, 2);
+        assert_eq!(report.get_prediction_sum(2)?, 21 + 36 + 101);
+        assert_eq!(report.get_prediction_sum(0)?, 15 + 21 + 45);
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+
+use common::error::Result;
+
+use crate::oasis::rational::Rational;
+use crate::oasis::value::{overflow_error, Number};
+
+// the polynomial generating an area's history, in terms of each value's position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialFit {
+    pub degree: usize,
+    // from the constant term up, with none for the zero polynomial
+    pub coefficients: Vec<Rational>,
+    // whether a row of zeros was reached within the history, rather than only fitting it
+    // because every short enough history fits some polynomial
+    pub is_polynomial: bool,
+}
+
+impl PolynomialFit {
+    // expands newton's forward difference formula, where each leading difference is
+    // multiplied by x(x - 1)...(x - row + 1) / row!
+    pub fn from_leading_differences<T: Number>(
+        leading_differences: &[T],
+        is_polynomial: bool,
+    ) -> Result<Self> {
+        let overflow = overflow_error::<Rational>;
+        let mut coefficients: Vec<Rational> = vec![Rational::zero(); leading_differences.len()];
+        let mut basis: Vec<Rational> = vec![Rational::integer(1)];
+
+        for (row, difference) in leading_differences.iter().enumerate() {
+            if row > 0 {
+                // multiplies the basis by (x - row + 1) / row
+                let shift = Rational::integer(row as i128 - 1);
+                let divisor = Rational::integer(row as i128);
+                let mut next_basis = vec![Rational::zero(); basis.len() + 1];
+                for (power, coefficient) in basis.iter().enumerate() {
+                    let coefficient = coefficient.checked_div(&divisor).ok_or_else(overflow)?;
+                    next_basis[power + 1] = next_basis[power + 1]
+                        .checked_add(&coefficient)
+                        .ok_or_else(overflow)?;
+                    next_basis[power] = coefficient
+                        .checked_mul(&shift)
+                        .and_then(|product| next_basis[power].checked_sub(&product))
+                        .ok_or_else(overflow)?;
+                }
+                basis = next_basis;
+            }
+
+            let difference = difference.to_rational().ok_or_else(overflow)?;
+            for (power, coefficient) in basis.iter().enumerate() {
+                coefficients[power] = coefficient
+                    .checked_mul(&difference)
+                    .and_then(|term| coefficients[power].checked_add(&term))
+                    .ok_or_else(overflow)?;
+            }
+        }
+
+        while coefficients
+            .last()
+            .is_some_and(|coefficient| coefficient.is_zero())
+        {
+            coefficients.pop();
+        }
+
+        Ok(Self {
+            degree: coefficients.len().saturating_sub(1),
+            coefficients,
+            is_polynomial,
+        })
+    }
+
+    pub fn has_integer_coefficients(&self) -> bool {
+        self.coefficients
+            .iter()
+            .all(|coefficient| coefficient.is_integer())
+    }
+}
+
+// written highest power first, e.g. 1/2x^2 + 3/2x + 1
+impl fmt::Display for PolynomialFit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .peekable();
+        if terms.peek().is_none() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (power, coefficient) in terms {
+            let negative = coefficient.numerator() < 0;
+            // unsigned, as i128::MIN has no positive i128 counterpart
+            let numerator = coefficient.numerator().unsigned_abs();
+            let magnitude = match coefficient.denominator() {
+                1 => numerator.to_string(),
+                denominator => format!("{}/{}", numerator, denominator),
+            };
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+
+            let magnitude = if power > 0 && magnitude == "1" {
+                String::new()
+            } else {
+                magnitude
+            };
+            match power {
+                0 => write!(f, "{}", magnitude)?,
+                1 => write!(f, "{}x", magnitude)?,
+                _ => write!(f, "{}x^{}", magnitude, power)?,
+            }
+        }
+        Ok(())
+    }
+}
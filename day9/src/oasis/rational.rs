@@ -0,0 +1,168 @@
+use std::fmt;
+
+use crate::oasis::value::Number;
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// an exact fraction, always kept in its lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        // reduced as magnitudes, since i128::MIN's has no positive i128 to divide by
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs());
+        let magnitude = numerator.unsigned_abs() / divisor;
+        let numerator = if (numerator < 0) != (denominator < 0) {
+            0i128.checked_sub_unsigned(magnitude)?
+        } else {
+            i128::try_from(magnitude).ok()?
+        };
+        Some(Self {
+            numerator,
+            denominator: i128::try_from(denominator.unsigned_abs() / divisor).ok()?,
+        })
+    }
+
+    pub fn integer(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Number for Rational {
+    const NAME: &'static str = "rational";
+
+    fn zero() -> Self {
+        Self::integer(0)
+    }
+
+    fn from_i64(value: i64) -> Option<Self> {
+        Some(Self::integer(value as i128))
+    }
+
+    // either an integer or a fraction such as 3/4
+    fn parse(value: &str) -> Option<Self> {
+        match value.split_once('/') {
+            Some((numerator, denominator)) => {
+                Self::new(numerator.parse().ok()?, denominator.parse().ok()?)
+            }
+            None => Some(Self::integer(value.parse().ok()?)),
+        }
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        // sharing the common factor of the denominators keeps intermediate values small
+        let divisor = gcd(self.denominator as u128, other.denominator as u128) as i128;
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator / divisor)?
+            .checked_add(other.numerator.checked_mul(self.denominator / divisor)?)?;
+        Self::new(
+            numerator,
+            (self.denominator / divisor).checked_mul(other.denominator)?,
+        )
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&Self {
+            numerator: other.numerator.checked_neg()?,
+            denominator: other.denominator,
+        })
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // cross cancelling first keeps intermediate values small
+        let first = gcd(self.numerator.unsigned_abs(), other.denominator as u128).max(1) as i128;
+        let second = gcd(other.numerator.unsigned_abs(), self.denominator as u128).max(1) as i128;
+        Self::new(
+            (self.numerator / first).checked_mul(other.numerator / second)?,
+            (self.denominator / second).checked_mul(other.denominator / first)?,
+        )
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        let reciprocal = Self::new(other.denominator, other.numerator)?;
+        self.checked_mul(&reciprocal)
+    }
+
+    fn to_rational(&self) -> Option<Rational> {
+        Some(*self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rationals_stay_in_lowest_terms() {
+        assert_eq!(Rational::new(6, -4), Rational::parse("-3/2"));
+        assert_eq!(Rational::new(1, 0), None);
+        assert_eq!(Rational::parse("4/2"), Some(Rational::integer(2)));
+
+        let half = Rational::new(1, 2).unwrap();
+        let third = Rational::new(1, 3).unwrap();
+        assert_eq!(half.checked_add(&third), Rational::new(5, 6));
+        assert_eq!(half.checked_sub(&third), Rational::new(1, 6));
+        assert_eq!(half.checked_mul(&third), Rational::new(1, 6));
+        assert_eq!(half.checked_div(&third), Rational::new(3, 2));
+        assert_eq!(half.checked_div(&Rational::zero()), None);
+        assert_eq!(Rational::new(3, 2).unwrap().to_string(), "3/2");
+    }
+
+    #[test]
+    fn rationals_report_overflow() {
+        let huge = Rational::integer(i128::MAX);
+        assert_eq!(huge.checked_add(&Rational::integer(1)), None);
+        assert_eq!(huge.checked_mul(&Rational::integer(2)), None);
+        assert_eq!(Rational::integer(i128::MIN).checked_sub(&huge), None);
+
+        assert_eq!(
+            Rational::new(i128::MIN, i128::MIN),
+            Some(Rational::integer(1))
+        );
+        assert_eq!(
+            Rational::new(i128::MIN, 2),
+            Some(Rational::integer(i128::MIN / 2))
+        );
+        assert_eq!(Rational::new(i128::MIN, -1), None);
+        assert_eq!(Rational::new(1, i128::MIN), None);
+    }
+}
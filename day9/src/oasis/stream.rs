@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use common::error::{Error, Result};
+
+use crate::oasis::value::{overflow_error, Number, Value};
+
+// an area accepting values as they arrive, keeping only the last value of each row of its
+// difference table, so a push takes as long as the history's degree rather than its length
+#[derive(Debug, Clone)]
+pub struct AreaStream<T = i64> {
+    value_count: usize,
+    // trailing zeros are dropped, as the rows beyond them only ever hold zeros
+    last_diagonal: Vec<T>,
+    next_prediction: Option<T>,
+}
+
+impl<T: Number> Default for AreaStream<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Number> AreaStream<T> {
+    pub fn new() -> Self {
+        Self {
+            value_count: 0,
+            last_diagonal: vec![],
+            next_prediction: None,
+        }
+    }
+
+    // leaves the stream as it was when the value overflows the difference table
+    pub fn push(&mut self, value: T) -> Result<()> {
+        let mut last_diagonal: Vec<T> = Vec::with_capacity(self.last_diagonal.len() + 1);
+        last_diagonal.push(value);
+
+        // a new row is started by each value, but each dropped zero leaves the row above as is
+        let row_count = self.value_count + 1;
+        while last_diagonal.len() < row_count {
+            let row = last_diagonal.len();
+            let previous = &last_diagonal[row - 1];
+            let next = match self.last_diagonal.get(row - 1) {
+                Some(previous_last) => previous
+                    .checked_sub(previous_last)
+                    .ok_or_else(overflow_error::<T>)?,
+                None if previous.is_zero() => break,
+                None => previous.clone(),
+            };
+            last_diagonal.push(next);
+        }
+        while last_diagonal.last().is_some_and(|last| last.is_zero()) {
+            last_diagonal.pop();
+        }
+
+        let next_prediction = last_diagonal
+            .iter()
+            .try_fold(T::zero(), |sum, last| sum.checked_add(last))
+            .ok_or_else(overflow_error::<T>)?;
+
+        self.value_count = row_count;
+        self.last_diagonal = last_diagonal;
+        self.next_prediction = Some(next_prediction);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.value_count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value_count == 0
+    }
+
+    pub fn last_diagonal(&self) -> &[T] {
+        &self.last_diagonal
+    }
+
+    pub fn degree(&self) -> usize {
+        self.last_diagonal.len().saturating_sub(1)
+    }
+
+    // none until the first value arrives
+    pub fn next_prediction(&self) -> Option<&T> {
+        self.next_prediction.as_ref()
+    }
+}
+
+// many areas' streams, each keyed by the area's id
+#[derive(Debug, Clone)]
+pub struct StreamReport<K = String, T = i64> {
+    streams: HashMap<K, AreaStream<T>>,
+}
+
+impl<K: Eq + Hash, T: Number> Default for StreamReport<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash, T: Number> StreamReport<K, T> {
+    pub fn new() -> Self {
+        Self {
+            streams: HashMap::new(),
+        }
+    }
+
+    pub fn push(&mut self, area_id: K, value: T) -> Result<()> {
+        self.streams.entry(area_id).or_default().push(value)
+    }
+
+    pub fn get(&self, area_id: &K) -> Option<&AreaStream<T>> {
+        self.streams.get(area_id)
+    }
+
+    pub fn area_ids(&self) -> impl Iterator<Item = &K> {
+        self.streams.keys()
+    }
+
+    pub fn get_next_value_area_prediction_sum(&self) -> Result<T> {
+        self.streams
+            .values()
+            .filter_map(|stream| stream.next_prediction())
+            .try_fold(T::zero(), |sum, prediction| sum.checked_add(prediction))
+            .ok_or_else(overflow_error::<T>)
+    }
+}
+
+impl<T: Number> StreamReport<String, T> {
+    // a reading written as `<area id> <value>`
+    pub fn push_line(&mut self, line: &str) -> Result<()> {
+        let mut parts = line.split_whitespace();
+        let area_id = parts.next().ok_or(Error::missing("an area id"))?;
+        let value = parts.next().ok_or(Error::missing("a value"))?;
+        if let Some(extra) = parts.next() {
+            return Err(
+                Error::invalid(&format!("Unexpected {} after the value", extra))
+                    .at_column_of(line, extra),
+            );
+        }
+
+        let value = Value::<T>::try_from(value).map_err(|error| error.at_column_of(line, value))?;
+        self.push(area_id.to_string(), value.get().clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::oasis::area::Area;
+
+    #[test]
+    fn streams_predict_as_values_arrive() -> Result<()> {
+        for line in include_str!("../../example.txt").lines() {
+            let area: Area = Area::try_from(line)?;
+            let mut stream: AreaStream = AreaStream::new();
+            for (index, value) in area.history().iter().enumerate() {
+                stream.push(*value.get())?;
+                let so_far: Area = Area::new(area.history()[..=index].to_vec())?;
+                assert_eq!(stream.next_prediction(), Some(&so_far.predict(1)?));
+            }
+            assert_eq!(stream.degree(), area.degree());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn streams_keep_only_as_many_rows_as_their_degree() -> Result<()> {
+        let mut stream: AreaStream = AreaStream::new();
+        assert_eq!(stream.next_prediction(), None);
+        for value in 0..1000 {
+            stream.push(3 * value + 1)?;
+        }
+        assert_eq!(stream.len(), 1000);
+        assert_eq!(stream.last_diagonal(), &[2998, 3]);
+        assert_eq!(stream.next_prediction(), Some(&3001));
+
+        // leaving the line behind brings back the rows that were dropped
+        stream.push(0)?;
+        assert_eq!(stream.degree(), 1000);
+        Ok(())
+    }
+
+    #[test]
+    fn overflowing_pushes_leave_streams_untouched() -> Result<()> {
+        let mut stream: AreaStream<i32> = AreaStream::new();
+        stream.push(i32::MIN)?;
+        let error = stream.push(i32::MAX).unwrap_err();
+        assert_eq!(error.to_string(), "Prediction overflows i32");
+        assert_eq!(stream.len(), 1);
+        assert_eq!(stream.next_prediction(), Some(&i32::MIN));
+        Ok(())
+    }
+
+    #[test]
+    fn stream_reports_key_areas_by_id() -> Result<()> {
+        let mut report: StreamReport = StreamReport::new();
+        let lines = include_str!("../../example.txt").lines();
+        let readings: Vec<Vec<&str>> = lines
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        // readings from each area arrive interleaved
+        for index in 0..readings[0].len() {
+            for (area, values) in readings.iter().enumerate() {
+                report.push_line(&format!("area-{} {}", area, values[index]))?;
+            }
+        }
+
+        assert_eq!(report.area_ids().count(), 3);
+        assert_eq!(
+            report
+                .get(&String::from("area-2"))
+                .unwrap()
+                .next_prediction(),
+            Some(&68)
+        );
+        assert_eq!(report.get_next_value_area_prediction_sum()?, 114);
+
+        let error = report.push_line("area-1 4x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse \"4x\" into a number at column 8"
+        );
+        let error = report.push_line("area-1").unwrap_err();
+        assert_eq!(error.to_string(), "Expected a value");
+        Ok(())
+    }
+}
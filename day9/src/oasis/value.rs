@@ -0,0 +1,96 @@
+use std::fmt;
+
+use common::error::{Error, Result};
+
+use crate::oasis::rational::Rational;
+
+// the numeric types values can be recorded & predicted in, where arithmetic reports overflow
+// as none rather than wrapping
+pub trait Number: Sized + Clone + PartialEq + fmt::Debug + fmt::Display {
+    const NAME: &'static str;
+
+    fn zero() -> Self;
+    fn from_i64(value: i64) -> Option<Self>;
+    fn parse(value: &str) -> Option<Self>;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    // only used where integer division is known to be exact
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    fn to_rational(&self) -> Option<Rational>;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+macro_rules! impl_number_for_integer {
+    ($integer:ty) => {
+        impl Number for $integer {
+            const NAME: &'static str = stringify!($integer);
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn from_i64(value: i64) -> Option<Self> {
+                <$integer>::try_from(value).ok()
+            }
+
+            fn parse(value: &str) -> Option<Self> {
+                value.parse::<$integer>().ok()
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$integer>::checked_add(*self, *other)
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                <$integer>::checked_sub(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$integer>::checked_mul(*self, *other)
+            }
+
+            fn checked_div(&self, other: &Self) -> Option<Self> {
+                <$integer>::checked_div(*self, *other)
+            }
+
+            fn to_rational(&self) -> Option<Rational> {
+                Some(Rational::integer(i128::from(*self)))
+            }
+        }
+    };
+}
+
+impl_number_for_integer!(i32);
+impl_number_for_integer!(i64);
+impl_number_for_integer!(i128);
+
+pub fn overflow_error<T: Number>() -> Error {
+    Error::invalid(&format!("Prediction overflows {}", T::NAME))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Value<T>(T);
+
+impl<T: Number> Value<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Number> TryFrom<&str> for Value<T> {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self> {
+        match T::parse(value) {
+            Some(parsed_value) => Ok(Self(parsed_value)),
+            None => Err(Error::invalid_number(value)),
+        }
+    }
+}